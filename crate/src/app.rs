@@ -1,6 +1,7 @@
 use page::{
-    AssetsPage, BasicFormPage, ButtonPage, CardPage, CarouselPage, DropDownPage, FormPage,
-    HomePage, LayoutsPage, ModalPage, NavbarPage, SpinnerPage, StylistPage, TextPage, TooltipPage,
+    AppShellPage, AssetsPage, BasicFormPage, ButtonGroupPage, ButtonPage, CardPage, CarouselPage,
+    DropDownPage, FormPage, HomePage, LayoutsPage, ModalPage, NavbarPage, SpinnerPage,
+    SplitButtonPage, StylistPage, TextPage, ThemePage, TooltipPage,
 };
 use yew::prelude::*;
 use yew_router::{prelude::*, route::Route, switch::Permissive, Switch};
@@ -43,6 +44,14 @@ pub enum AppRouter {
     TooltipPath,
     #[to = "/stylist"]
     StylistPagePath,
+    #[to = "/theme"]
+    ThemePagePath,
+    #[to = "/app-shell"]
+    AppShellPath,
+    #[to = "/button-group"]
+    ButtonGroupPath,
+    #[to = "/split-button"]
+    SplitButtonPath,
     #[to = "/page-not-found"]
     PageNotFound(Permissive<String>),
 }
@@ -120,6 +129,18 @@ impl Component for App {
                         <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
                         <RouterAnchor<AppRouter> route=AppRouter::StylistPagePath>{"Stylist"}</RouterAnchor<AppRouter>>
                     </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ThemePagePath>{"Theme"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::AppShellPath>{"App Shell"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::ButtonGroupPath>{"Button Group"}</RouterAnchor<AppRouter>>
+                        </Item>
+                        <Item layouts=vec!(ItemLayout::ItXs(12)) class_name="component-link">
+                            <RouterAnchor<AppRouter> route=AppRouter::SplitButtonPath>{"Split Button"}</RouterAnchor<AppRouter>>
+                        </Item>
                     </Container>
                 </Item>
                 <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItL(10) )>
@@ -141,6 +162,10 @@ impl Component for App {
                                 AppRouter::CarouselPath => html!{<CarouselPage/>},
                                 AppRouter::TooltipPath => html!{<TooltipPage/>},
                                 AppRouter::StylistPagePath => html!{<StylistPage/>},
+                                AppRouter::ThemePagePath => html!{<ThemePage/>},
+                                AppRouter::AppShellPath => html!{<AppShellPage/>},
+                                AppRouter::ButtonGroupPath => html!{<ButtonGroupPage/>},
+                                AppRouter::SplitButtonPath => html!{<SplitButtonPage/>},
                                 AppRouter::PageNotFound(Permissive(None)) => html!{"Page not found"},
                                 AppRouter::PageNotFound(Permissive(Some(missed_route))) => html!{format!("Page '{}' not found", missed_route)}
                             }
@@ -0,0 +1,84 @@
+use super::highlighters::get_app_shell;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::app_shell::AppShell;
+use yew_styles::navbar::{navbar_container::NavbarContainer, navbar_item::NavbarItem};
+use yew_styles::styles::{Palette, Style};
+
+pub struct AppShellPage;
+
+impl Component for AppShellPage {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self {}
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"App Shell Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"app_shell"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_app_shell()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"header: "}</b>{"content of the navbar in the header, usually "}<code>{"NavbarContainer"}</code>{" components. Default "}<code>{"html! {}"}</code>{"."}</li>
+                    <li><b>{"branch: "}</b>{"vnode embedded in the beginning of the navbar, useful to include a branch logo."}</li>
+                    <li><b>{"navbar_palette: "}</b>{"type navbar palette. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"navbar_style: "}</b>{"navbar styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"fixed: "}</b>{"the location of the navbar which is fixed. Options included in "}<code>{"Fixed"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"sidebar: "}</b>{"content of the sidebar, without it there is no sidebar. In screens smaller than the "}<code>{"l"}</code>{" breakpoint it is an off-canvas drawer. Default "}<code>{"html! {}"}</code>{"."}</li>
+                    <li><b>{"sidebar_width: "}</b>{"width of the sidebar. Default "}<code>{"250px"}</code>{"."}</li>
+                    <li><b>{"footer: "}</b>{"content of the footer, without it there is no footer. Default "}<code>{"html! {}"}</code>{"."}</li>
+                    <li><b>{"ontoggle_sidebar_signal: "}</b>{"signal when the sidebar is toggled, "}<code>{"true"}</code>{" when it is collapsed in big screens or opened in small screens. Default "}<code>{"noop()"}</code>{"."}</li>
+                    <li><b>{"breakpoints: "}</b>{"the sidebar is a drawer in screens smaller than the "}<code>{"l"}</code>{" breakpoint. Default "}<code>{"Breakpoints::default()"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h2>{"Visual example"}</h2>
+                <AppShell
+                    class_name="app-shell-example"
+                    navbar_palette=Palette::Primary
+                    navbar_style=Style::Regular
+                    header=html! {
+                        <NavbarContainer>
+                            <NavbarItem><span>{"Reports"}</span></NavbarItem>
+                            <NavbarItem><span>{"Profile"}</span></NavbarItem>
+                        </NavbarContainer>
+                    }
+                    sidebar=html! {
+                        <ul>
+                            <li>{"Dashboard"}</li>
+                            <li>{"Projects"}</li>
+                            <li>{"Settings"}</li>
+                        </ul>
+                    }
+                    footer=html! {<span>{"Footer"}</span>}
+                >
+                    {(1..20).map(|line| html! {<p>{format!("Content line {}", line)}</p>}).collect::<Html>()}
+                </AppShell>
+            </>
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use super::highlighters::button_group_code;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::{
+    button::Button,
+    button_group::{ButtonGroup, Selection},
+    styles::{Palette, Size, Style},
+};
+
+#[derive(Clone, PartialEq)]
+pub enum View {
+    List,
+    Grid,
+    Map,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum TextFormat {
+    Bold,
+    Italic,
+    Underline,
+}
+
+pub struct ButtonGroupPage {
+    link: ComponentLink<Self>,
+    view: View,
+    formats: Vec<TextFormat>,
+    action: String,
+}
+
+pub enum Msg {
+    ChangeView(Vec<View>),
+    ChangeFormats(Vec<TextFormat>),
+    Action(String),
+}
+
+impl Component for ButtonGroupPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        ButtonGroupPage {
+            link,
+            view: View::List,
+            formats: vec![],
+            action: String::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeView(views) => {
+                self.view = views[0].clone();
+            }
+            Msg::ChangeFormats(formats) => {
+                self.formats = formats;
+            }
+            Msg::Action(action) => {
+                self.action = action;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Button Group Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"button_group"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=button_group_code()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"button_palette: "}</b>{"type palette of the buttons. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"button_style: "}</b>{"style of the buttons. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Outline"}</code>{"."}</li>
+                    <li><b>{"active_style: "}</b>{"style of the active buttons. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"button_size: "}</b>{"size of the buttons. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"selection: "}</b>{"how the buttons are selected, with a selection the buttons have "}<code>{"aria-pressed"}</code>{". Options included in "}<code>{"Selection"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"values: "}</b>{"value of each button in the same order of the buttons, it can be any type. With a selection there must be one value for each button. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"selected: "}</b>{"active values. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"onchange_signal: "}</b>{"signal with the active values when the selection changes."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <h3>{"Single selection"}</h3>
+                <ButtonGroup<View>
+                    button_palette=Palette::Primary
+                    button_size=Size::Small
+                    selection=Selection::Single
+                    values=vec![View::List, View::Grid, View::Map]
+                    selected=vec![self.view.clone()]
+                    onchange_signal=self.link.callback(Msg::ChangeView)
+                >
+                    <Button>{"List"}</Button>
+                    <Button>{"Grid"}</Button>
+                    <Button>{"Map"}</Button>
+                </ButtonGroup<View>>
+                <p>{format!("View: {}", get_view_name(self.view.clone()))}</p>
+
+                <h3>{"Multiple selection"}</h3>
+                <ButtonGroup<TextFormat>
+                    button_palette=Palette::Standard
+                    button_style=Style::Light
+                    active_style=Style::Outline
+                    selection=Selection::Multiple
+                    values=vec![TextFormat::Bold, TextFormat::Italic, TextFormat::Underline]
+                    selected=self.formats.clone()
+                    onchange_signal=self.link.callback(Msg::ChangeFormats)
+                >
+                    <Button><b>{"B"}</b></Button>
+                    <Button><i>{"I"}</i></Button>
+                    <Button><u>{"U"}</u></Button>
+                </ButtonGroup<TextFormat>>
+                <p>{format!("Formats selected: {}", self.formats.len())}</p>
+
+                <h3>{"Actions"}</h3>
+                <ButtonGroup<()> button_palette=Palette::Info>
+                    <Button onclick_signal=self.link.callback(|_| Msg::Action("Previous".to_string()))>{"Previous"}</Button>
+                    <Button onclick_signal=self.link.callback(|_| Msg::Action("Today".to_string()))>{"Today"}</Button>
+                    <Button onclick_signal=self.link.callback(|_| Msg::Action("Next".to_string()))>{"Next"}</Button>
+                </ButtonGroup<()>>
+                <p>{format!("Action: {}", self.action)}</p>
+            </>
+        }
+    }
+}
+
+fn get_view_name(view: View) -> String {
+    match view {
+        View::List => "list".to_string(),
+        View::Grid => "grid".to_string(),
+        View::Map => "map".to_string(),
+    }
+}
@@ -1,18 +1,26 @@
-use super::highlighters::button_code;
+use super::highlighters::{button_code, button_icons_code, button_toggle_link_code};
 use yew::prelude::*;
+use yew_assets::{
+    controller_assets::ControllerIcon, editing_assets::EditingIcon, ux_assets::UxIcon,
+};
 use yew_prism::Prism;
 use yew_styles::{
-    button::Button,
+    button::{Button, ButtonIcon, ButtonType, IconOnly},
+    spinner::SpinnerType,
     styles::{get_palette, get_size, get_style, Palette, Size, Style},
 };
 
 pub struct ButtonPage {
     link: ComponentLink<Self>,
     button_palette: Vec<Vec<String>>,
+    loading: bool,
+    bold: bool,
 }
 
 pub enum Msg {
     ChangeType(String, usize, usize),
+    ToggleLoading,
+    ToggleBold(bool),
 }
 
 impl Component for ButtonPage {
@@ -23,6 +31,8 @@ impl Component for ButtonPage {
         ButtonPage {
             link,
             button_palette: vec![vec!["".to_string(); 3]; 3],
+            loading: false,
+            bold: false,
         }
     }
 
@@ -31,6 +41,12 @@ impl Component for ButtonPage {
             Msg::ChangeType(button_type, index_style, index_size) => {
                 self.button_palette[index_style][index_size] = button_type;
             }
+            Msg::ToggleLoading => {
+                self.loading = !self.loading;
+            }
+            Msg::ToggleBold(bold) => {
+                self.bold = bold;
+            }
         }
         true
     }
@@ -56,9 +72,24 @@ impl Component for ButtonPage {
                 <h2>{"Properties"}</h2>
                 <ul>
                     <li><b>{"button_palette: "}</b>{"type button style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
-                    <li><b>{"button_size: "}</b>{"three diffent button standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"button_size: "}</b>{"five diffent button standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"button_style: "}</b>{"button styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
-                    <li><b>{"onclick_signal: "}</b>{"click event for button. Required."}</li>
+                    <li><b>{"loading: "}</b>{"show a spinner with the color of the text instead of the content and ignore the clicks. The width of the button does not change. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"loading_spinner: "}</b>{"spinner type showed when the button is loading. Options included in "}<code>{"SpinnerType"}</code>{". Default "}<code>{"Circle"}</code>{"."}</li>
+                    <li><b>{"icon_left: "}</b>{"icon before the content. Options included in "}<code>{"ButtonIcon"}</code>{" with the icons of yew_assets. Its size depends on the button size. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"icon_right: "}</b>{"icon after the content. Options included in "}<code>{"ButtonIcon"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"icon_only: "}</b>{"show only an icon instead of the content. "}<code>{"IconOnly::new(icon, label)"}</code>{" requires a label that is read by the screen readers. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"toggle: "}</b>{"keep the button pressed or not after each click, the state is exposed with "}<code>{"aria-pressed"}</code>{". Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"pressed: "}</b>{"whether the toggle button is pressed. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"aria_pressed: "}</b>{"pressed state read by the screen readers when a parent controls it, like the active buttons of a "}<code>{"ButtonGroup"}</code>{". Toggle buttons use "}<code>{"pressed"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"aria_haspopup: "}</b>{"kind of popup opened by the button, like "}<code>{"menu"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"aria_expanded: "}</b>{"whether the popup opened by the button is shown. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"href: "}</b>{"link where the button navigates, with it the button is rendered as an anchor. Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"target: "}</b>{"where the link of the button is opened, like "}<code>{"_blank"}</code>{". Default "}<code>{"\"\""}</code>{"."}</li>
+                    <li><b>{"disabled: "}</b>{"whether the button is disabled. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"button_type: "}</b>{"type of the button inside of a form. Options included in "}<code>{"ButtonType"}</code>{". Default "}<code>{"Button"}</code>{"."}</li>
+                    <li><b>{"onclick_signal: "}</b>{"click event for button. Default "}<code>{"noop()"}</code>{"."}</li>
+                    <li><b>{"ontoggle_signal: "}</b>{"signal when the toggle button is pressed or released with the new state. Default "}<code>{"noop()"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id."}</li>
@@ -66,7 +97,81 @@ impl Component for ButtonPage {
                     <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
                 </ul>
 
+                <h3>{"Icons"}</h3>
+                <Prism
+                    code=button_icons_code()
+                    language="rust"
+                />
+
+                <h3>{"Toggle and link"}</h3>
+                <Prism
+                    code=button_toggle_link_code()
+                    language="rust"
+                />
+
                 <h2>{"Visual examples"}</h2>
+                <h3>{"TOGGLE, LINK AND DISABLED"}</h3>
+                <div class="show-size">
+                    <Button
+                        toggle=true
+                        pressed=self.bold
+                        ontoggle_signal=self.link.callback(Msg::ToggleBold)
+                    >{if self.bold { "Bold on" } else { "Bold off" }}</Button>
+                    <Button
+                        button_palette=Palette::Link
+                        button_style=Style::Outline
+                        href="https://github.com/spielrs/yew_styles"
+                        target="_blank"
+                    >{"Repository"}</Button>
+                    <Button
+                        button_palette=Palette::Primary
+                        button_type=ButtonType::Submit
+                        disabled=true
+                    >{"Disabled"}</Button>
+                </div>
+                <h3>{"ICONS"}</h3>
+                <div class="show-size">
+                    <Button
+                        button_palette=Palette::Primary
+                        icon_left=Some(ButtonIcon::Controller(ControllerIcon::ArrowDownCircle))
+                    >{"Download"}</Button>
+                    <Button
+                        button_palette=Palette::Info
+                        button_style=Style::Outline
+                        icon_right=Some(ButtonIcon::Controller(ControllerIcon::ChevronRight))
+                    >{"Next"}</Button>
+                    <Button
+                        button_palette=Palette::Standard
+                        button_style=Style::Light
+                        button_size=Size::Small
+                        icon_only=Some(IconOnly::new(ButtonIcon::Editing(EditingIcon::Edit), "Edit"))
+                    />
+                    <Button
+                        button_palette=Palette::Danger
+                        button_size=Size::Big
+                        icon_only=Some(IconOnly::new(ButtonIcon::Ux(UxIcon::Settings), "Settings"))
+                    />
+                </div>
+                <h3>{"LOADING"}</h3>
+                <div class="show-size">
+                    <Button
+                        onclick_signal=self.link.callback(|_| Msg::ToggleLoading)
+                        button_palette=Palette::Primary
+                        loading=self.loading
+                    >{"Save changes"}</Button>
+                    <Button
+                        onclick_signal=self.link.callback(|_| Msg::ToggleLoading)
+                        button_palette=Palette::Success
+                        button_style=Style::Outline
+                        loading=self.loading
+                        loading_spinner=SpinnerType::Wave
+                    >{"Send"}</Button>
+                    <Button
+                        onclick_signal=self.link.callback(|_| Msg::ToggleLoading)
+                        button_palette=Palette::Standard
+                        button_style=Style::Light
+                    >{if self.loading { "Stop loading" } else { "Start loading" }}</Button>
+                </div>
                 <div class="container-button">
                     <div class="buttons-example">
                         {get_button_styles(self.link.clone(), self.button_palette.clone())}
@@ -87,7 +192,14 @@ fn to_first_upercase(word: &str) -> String {
 }
 
 fn get_button_styles(link: ComponentLink<ButtonPage>, value: Vec<Vec<String>>) -> Html {
-    let styles: Vec<Style> = vec![Style::Regular, Style::Light, Style::Outline];
+    let styles: Vec<Style> = vec![
+        Style::Regular,
+        Style::Light,
+        Style::Outline,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ];
 
     styles
         .into_iter()
@@ -109,7 +221,13 @@ fn get_sizes(
     value: Vec<Vec<String>>,
     index_style: usize,
 ) -> Html {
-    let sizes: Vec<Size> = vec![Size::Small, Size::Medium, Size::Big];
+    let sizes: Vec<Size> = vec![
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Big,
+        Size::XLarge,
+    ];
 
     sizes
         .into_iter()
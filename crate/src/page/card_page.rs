@@ -100,7 +100,7 @@ impl Component for CardPage {
                 <h2>{"Propeties"}</h2>
                 <ul>
                     <li><b>{"card_palette: "}</b>{"type card purpose style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
-                    <li><b>{"card_size: "}</b>{"three diffent card standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"card_size: "}</b>{"five diffent card standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"card_style: "}</b>{"card styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
                     <li><b>{"onclick_signal: "}</b>{"click event for card."}</li>
                     <li><b>{"ondrag_signal: "}</b>{"a dragged item (element or text selection) is dragged."}</li>
@@ -217,7 +217,13 @@ impl Component for CardPage {
 }
 
 fn get_sizes() -> Html {
-    let sizes: Vec<Size> = vec![Size::Small, Size::Medium, Size::Big];
+    let sizes: Vec<Size> = vec![
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Big,
+        Size::XLarge,
+    ];
 
     sizes
         .into_iter()
@@ -243,7 +249,14 @@ fn get_sizes() -> Html {
 }
 
 fn get_styles() -> Html {
-    let styles: Vec<Style> = vec![Style::Regular, Style::Outline, Style::Light];
+    let styles: Vec<Style> = vec![
+        Style::Regular,
+        Style::Outline,
+        Style::Light,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ];
 
     styles
         .into_iter()
@@ -154,7 +154,7 @@ impl Component for CarouselPage {
                     <li><b>{"prev_signal: "}</b>{"click event for the left control button to go to previous image. Required."}</li>
                     <li><b>{"next_signal: "}</b>{"click event for the left control button to go to the next image. Required."}</li>
                     <li><b>{"controls_palette: "}</b>{"type controls style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
-                    <li><b>{"controls_size: "}</b>{"three diffent controls standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"controls_size: "}</b>{"five diffent controls standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"controls_style: "}</b>{"controls styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
@@ -62,7 +62,7 @@ impl Component for DropDownPage {
                     <li><b>{"main_content: "}</b>{"clickeable content to show the dropdown. Required"}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"dropdown_palette: "}</b>{"type dropdown style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
-                    <li><b>{"dropdown_size: "}</b>{"three diffent dropdown standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"dropdown_size: "}</b>{"five diffent dropdown standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"dropdown_style: "}</b>{"dropdown styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
                     <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
@@ -72,6 +72,7 @@ impl Component for DropDownPage {
                 <h2>{"Dropdown Item properties"}</h2>
                 <ul>
                     <li><b>{"onclick_signal: "}</b>{"click event for dropdown item. Default "}<code>{"noop()"}</code></li>
+                    <li><b>{"role: "}</b>{"accessible role of the item. Default "}<code>{"None"}</code></li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
                     <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
@@ -93,7 +94,13 @@ impl Component for DropDownPage {
 }
 
 fn get_sizes(link: ComponentLink<DropDownPage>) -> Html {
-    let sizes: Vec<Size> = vec![Size::Small, Size::Medium, Size::Big];
+    let sizes: Vec<Size> = vec![
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Big,
+        Size::XLarge,
+    ];
 
     sizes
         .into_iter()
@@ -121,7 +128,14 @@ fn get_sizes(link: ComponentLink<DropDownPage>) -> Html {
 }
 
 fn get_styles(link: ComponentLink<DropDownPage>) -> Html {
-    let styles: Vec<Style> = vec![Style::Regular, Style::Outline, Style::Light];
+    let styles: Vec<Style> = vec![
+        Style::Regular,
+        Style::Outline,
+        Style::Light,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ];
 
     styles
         .into_iter()
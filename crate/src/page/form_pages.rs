@@ -1,5 +1,9 @@
-use super::highlighters::{file_code, input_code, select_code, textarea_code};
+use super::highlighters::{
+    checkbox_code, file_code, form_model_code, form_values_code, input_code, radio_group_code,
+    select_code, textarea_code,
+};
 use crate::app::AppRouter;
+use serde_derive::Deserialize;
 use wasm_bindgen::JsCast;
 use web_sys::{File, HtmlOptionElement, Url};
 use yew::prelude::*;
@@ -7,12 +11,18 @@ use yew::services::ConsoleService;
 use yew_prism::Prism;
 use yew_router::prelude::*;
 use yew_styles::forms::{
+    form_checkbox::FormCheckbox,
+    form_component::Form,
     form_file::FormFile,
     form_group::{FormGroup, Orientation},
     form_input::{FormInput, InputType},
     form_label::FormLabel,
+    form_model::{FormModel, Validator},
+    form_radio_group::{FormRadioGroup, RadioOption},
     form_select::FormSelect,
+    form_submit::FormSubmit,
     form_textarea::FormTextArea,
+    form_values::FieldError,
 };
 use yew_styles::layouts::{
     container::{Container, Direction, Wrap},
@@ -20,19 +30,38 @@ use yew_styles::layouts::{
 };
 use yew_styles::styles::{Palette, Size};
 
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct SignIn {
+    email: String,
+    password: String,
+    #[serde(default)]
+    remember: bool,
+}
+
 pub struct FormPage {
     pub link: ComponentLink<Self>,
     pub value: Vec<String>,
     pub multiple_values: Vec<String>,
     pub file_path: String,
+    pub form_model: FormModel,
+    pub signed_in: Option<SignIn>,
+    pub toppings: Vec<String>,
+    pub plan: Option<String>,
 }
 
+const TOPPINGS: [&str; 3] = ["Cheese", "Mushrooms", "Olives"];
+
 pub enum Msg {
     Input(String, usize),
     Select(String, usize),
     MultipleSelect(Vec<String>),
     UploadFile(File),
     ErrorUploadImage,
+    ChangeModel(FormModel),
+    SignIn(Result<SignIn, Vec<FieldError>>),
+    Topping(String, bool),
+    AllToppings(bool),
+    Plan(String),
 }
 
 impl Component for FormPage {
@@ -44,6 +73,10 @@ impl Component for FormPage {
             value: vec!["".to_string(); 8],
             multiple_values: vec![],
             file_path: "".to_string(),
+            form_model: get_form_model(),
+            signed_in: None,
+            toppings: vec![],
+            plan: None,
         }
     }
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -64,6 +97,37 @@ impl Component for FormPage {
             Msg::ErrorUploadImage => {
                 ConsoleService::error("Error to upload image");
             }
+            Msg::ChangeModel(form_model) => {
+                self.form_model = form_model;
+                self.signed_in = None;
+            }
+            Msg::SignIn(result) => match result {
+                Ok(sign_in) => {
+                    self.signed_in = Some(sign_in);
+                }
+                Err(errors) => {
+                    for error in errors {
+                        ConsoleService::error(&format!("{}: {}", error.name, error.message));
+                    }
+                }
+            },
+            Msg::Topping(topping, checked) => {
+                self.toppings.retain(|item| *item != topping);
+
+                if checked {
+                    self.toppings.push(topping);
+                }
+            }
+            Msg::AllToppings(checked) => {
+                self.toppings = if checked {
+                    TOPPINGS.iter().map(|topping| topping.to_string()).collect()
+                } else {
+                    vec![]
+                };
+            }
+            Msg::Plan(plan) => {
+                self.plan = Some(plan);
+            }
         }
         true
     }
@@ -94,12 +158,55 @@ impl Component for FormPage {
                     <li><b>{"method: "}</b>{"the HTTP method to submit the form. Options included in "}<code>{"Method"}</code>
                         {". Default "}<code>{"Post"}</code>{"."}</li>
                     <li><b>{"name: "}</b>{"the name of the form."}</li>
+                    <li><b>{"form_model: "}</b>{"state and validators of the fields. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"onchange_model_signal: "}</b>{"signal with the form model updated after an input, a blur or a submit."}</li>
+                    <li><b>{"onsubmit_values_signal: "}</b>{"signal with the values of the fields deserialized by "}<code>{"name"}</code>
+                        {" in the type of the form or the list of "}<code>{"FieldError"}</code>{" when it is submitted."}</li>
                 </ul>
 
                 <p><b>{"Note: "}</b>{"the component triggers automatically "}
                     <code>{"prevent_default"}</code>{" once that the submit event is actined."}</p>
                 <p>{"The code example is in "}<RouterAnchor<AppRouter> route=AppRouter::BasicFormPath>{"Basic Form page"}</RouterAnchor<AppRouter>></p>
 
+                <h2>{"Form model"}</h2>
+                <p>{"The "}<code>{"FormModel"}</code>{" keeps the value, the touched and dirty flags and the errors of each field. "}
+                    {"The "}<code>{"Form"}</code>{" updates the fields of the model by "}<code>{"name"}</code>
+                    {" in every input and blur and it only emits "}<code>{"onsubmit_signal"}</code>{" when the model is valid. "}
+                    {"Pass the model to the form components with the "}<code>{"form_model"}</code>{" property to show the first error once the field is touched, the field gets the "}<code>{"error"}</code>{" class and "}<code>{"aria-invalid"}</code>{"."}</p>
+                <Prism
+                    code=form_model_code()
+                    language="rust"
+                />
+                <h3>{"Validators"}</h3>
+                <ul>
+                    <li><b>{"Required: "}</b>{"the value can not be empty."}</li>
+                    <li><b>{"MinLength: "}</b>{"minimum number of characters."}</li>
+                    <li><b>{"MaxLength: "}</b>{"maximum number of characters."}</li>
+                    <li><b>{"Pattern: "}</b>{"javascript regular expression that the whole value must match. Create it with "}<code>{"Validator::pattern"}</code>{", it returns an error if the regular expression is not valid."}</li>
+                    <li><b>{"Email: "}</b>{"the value must be an email address."}</li>
+                    <li><b>{"Custom: "}</b>{"closure that returns the error message when the value is invalid. Create it with "}<code>{"Validator::custom"}</code>{"."}</li>
+                </ul>
+                <p><b>{"Note: "}</b>{"every validator except "}<code>{"Required"}</code>{" and "}<code>{"Custom"}</code>{" is valid with an empty value."}</p>
+                <h3>{"Visual example"}</h3>
+                {get_form_model_example(self)}
+
+                <h2>{"Typed values"}</h2>
+                <p>{"The form can be typed with a struct that implements "}<code>{"serde::Deserialize"}</code>
+                    {", for example "}<code>{"Form<SignIn>"}</code>{". On submit the values of the fields are collected by "}<code>{"name"}</code>
+                    {" and "}<code>{"onsubmit_values_signal"}</code>{" emits the struct or the errors of every field that could not be deserialized."}</p>
+                <Prism
+                    code=form_values_code()
+                    language="rust"
+                />
+                <ul>
+                    <li>{"The text values are parsed to the type of the field, like numbers or "}<code>{"bool"}</code>{"."}</li>
+                    <li>{"Unchecked checkboxes and radios are not included, use "}<code>{"#[serde(default)]"}</code>{" to get "}<code>{"false"}</code>{" in a "}<code>{"bool"}</code>{" field."}</li>
+                    <li>{"An empty or missing value is "}<code>{"None"}</code>{" in an "}<code>{"Option"}</code>{" field, any other missing field is an error."}</li>
+                    <li>{"Multiple values with the same name, like the options of a multiple select, are a "}<code>{"Vec"}</code>{"."}</li>
+                    <li>{"The file inputs are not included."}</li>
+                </ul>
+                <p>{"The visual example of the form model is typed with "}<code>{"Form<SignIn>"}</code>{"."}</p>
+
                 <h2>{"Form group"}</h2>
                 <ul>
                     <li><b>{"orientation: "}</b>{"in which orientation will show the inputs, select and labels. Options included in "}
@@ -126,6 +233,8 @@ impl Component for FormPage {
                     <li><b>{"size: "}</b>{"the size of the submit. Options included in "}<code>{"Size"}</code>
                         {". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"disabled: "}</b>{"whether the form control is disabled."}</li>
+                    <li><b>{"loading: "}</b>{"show a spinner instead of the text and block the submit, keeping the width of the submit. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"loading_spinner: "}</b>{"spinner type showed when the submit is loading. Options included in "}<code>{"SpinnerType"}</code>{". Default "}<code>{"Circle"}</code>{"."}</li>
                 </ul>
 
                 <p>{"The code example is in "}<RouterAnchor<AppRouter> route=AppRouter::BasicFormPath>{"Basic Form page"}</RouterAnchor<AppRouter>></p>
@@ -165,6 +274,7 @@ impl Component for FormPage {
                     <li><b>{"step: "}</b>{"incremental values that are valid."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"form_model: "}</b>{"form model that shows the errors of the field by "}<code>{"name"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
                 </ul>
                 <h3>{"Visual example"}</h3>
                 {get_form_inputs(self)}
@@ -193,6 +303,7 @@ impl Component for FormPage {
                     <li><b>{"capture: "}</b>{"media capture input method in file upload controls."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"form_model: "}</b>{"form model that shows the errors of the field by "}<code>{"name"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
                 </ul>
                 <h3>{"Visual examples"}</h3>
                 {get_form_file(self)}
@@ -217,6 +328,7 @@ impl Component for FormPage {
                         this attribute represents the number of rows in the list that should be visible at one time."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"form_model: "}</b>{"form model that shows the errors of the field by "}<code>{"name"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
                 </ul>
                 <h3>{"Visual examples"}</h3>
                 {get_select_form(self)}
@@ -255,14 +367,148 @@ impl Component for FormPage {
                         {" is subject to spell checking by the underlying browser/OS."}</li>
                     <li><b>{"error_state: "}</b>{"error state for validation."}</li>
                     <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"form_model: "}</b>{"form model that shows the errors of the field by "}<code>{"name"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
                 </ul>
                 <h3>{"Visual examples"}</h3>
                 {get_textarea(self)}
+
+                <h2>{"Form checkbox"}</h2>
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=checkbox_code()
+                    language="rust"
+                />
+                <h3>{"Properties"}</h3>
+                <ul>
+                    <li><b>{"label: "}</b>{"text of the checkbox."}</li>
+                    <li><b>{"name: "}</b>{"the name of the checkbox."}</li>
+                    <li><b>{"value: "}</b>{"value submitted when the checkbox is checked. Default "}<code>{"on"}</code>{"."}</li>
+                    <li><b>{"checked: "}</b>{"whether the checkbox is checked. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"indeterminate: "}</b>{"neither checked nor unchecked, it is shown until the user changes the checkbox. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"checkbox_palette: "}</b>{"type palette of the checkbox. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"checkbox_size: "}</b>{"size of the checkbox. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"disabled: "}</b>{"whether the checkbox is disabled."}</li>
+                    <li><b>{"required: "}</b>{"the checkbox must be checked for the form to be submittable."}</li>
+                    <li><b>{"onchange_signal: "}</b>{"signal with the value and the checked state when the user changes it."}</li>
+                    <li><b>{"error_state: "}</b>{"error state for validation."}</li>
+                    <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"form_model: "}</b>{"form model that shows the errors of the field by "}<code>{"name"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                </ul>
+                <h3>{"Visual examples"}</h3>
+                {get_checkboxes(self)}
+
+                <h2>{"Form radio group"}</h2>
+                <h3>{"Code example"}</h3>
+                <Prism
+                    code=radio_group_code()
+                    language="rust"
+                />
+                <h3>{"Properties"}</h3>
+                <ul>
+                    <li><b>{"name: "}</b>{"the name of the radios in the group. Required."}</li>
+                    <li><b>{"options: "}</b>{"options of the group, created with "}<code>{"RadioOption::new"}</code>{" or "}<code>{"RadioOption::disabled"}</code>
+                        {". The value can be any type that implements "}<code>{"ToString"}</code>{"."}</li>
+                    <li><b>{"selected: "}</b>{"value of the selected option. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"radio_palette: "}</b>{"type palette of the radios. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"radio_size: "}</b>{"size of the radios. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"orientation: "}</b>{"in which orientation will show the options. Options included in "}<code>{"Orientation"}</code>
+                        {". Default "}<code>{"Vertical"}</code>{"."}</li>
+                    <li><b>{"disabled: "}</b>{"whether the group is disabled."}</li>
+                    <li><b>{"required: "}</b>{"an option must be selected for the form to be submittable."}</li>
+                    <li><b>{"onchange_signal: "}</b>{"signal with the value of the option selected."}</li>
+                    <li><b>{"error_state: "}</b>{"error state for validation."}</li>
+                    <li><b>{"error_message: "}</b>{"show error message when error_state is true."}</li>
+                    <li><b>{"form_model: "}</b>{"form model that shows the errors of the field by "}<code>{"name"}</code>{". Default "}<code>{"None"}</code>{"."}</li>
+                </ul>
+                <p><b>{"Note: "}</b>{"the arrow keys move the focus and the selection between the enabled options."}</p>
+                <h3>{"Visual examples"}</h3>
+                {get_radio_groups(self)}
             </>
         }
     }
 }
 
+fn get_form_model() -> FormModel {
+    FormModel::new()
+        .field(
+            "email",
+            "",
+            vec![
+                Validator::Required("Email is required".to_string()),
+                Validator::Email("Email is not valid".to_string()),
+            ],
+        )
+        .field(
+            "password",
+            "",
+            vec![
+                Validator::MinLength(8, "At least 8 characters".to_string()),
+                Validator::custom(|value| {
+                    if value.chars().any(|c| c.is_numeric()) {
+                        None
+                    } else {
+                        Some("At least one number".to_string())
+                    }
+                }),
+            ],
+        )
+}
+
+fn get_form_model_example(form_page: &FormPage) -> Html {
+    html! {
+        <Form<SignIn>
+            form_model=Some(form_page.form_model.clone())
+            onchange_model_signal=form_page.link.callback(Msg::ChangeModel)
+            onsubmit_values_signal=form_page.link.callback(Msg::SignIn)
+        >
+            <Container wrap=Wrap::Wrap direction=Direction::Row>
+                <Item layouts=vec!(ItemLayout::ItM(6), ItemLayout::ItXs(12))>
+                    <FormGroup orientation=Orientation::Vertical>
+                        <FormLabel text="Email:"/>
+                        <FormInput
+                            name="email"
+                            input_type=InputType::Email
+                            form_model=Some(form_page.form_model.clone())
+                        />
+                    </FormGroup>
+                </Item>
+                <Item layouts=vec!(ItemLayout::ItM(6), ItemLayout::ItXs(12))>
+                    <FormGroup orientation=Orientation::Vertical>
+                        <FormLabel text="Password:"/>
+                        <FormInput
+                            name="password"
+                            input_type=InputType::Password
+                            form_model=Some(form_page.form_model.clone())
+                        />
+                    </FormGroup>
+                </Item>
+                <Item layouts=vec!(ItemLayout::ItXs(12))>
+                    <FormGroup orientation=Orientation::Horizontal>
+                        <FormInput name="remember" input_type=InputType::Checkbox />
+                        <FormLabel text="Remember me"/>
+                    </FormGroup>
+                </Item>
+                <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(3))>
+                    <FormGroup>
+                        <FormSubmit value="Sign in" submit_palette=Palette::Primary />
+                    </FormGroup>
+                </Item>
+                <Item layouts=vec!(ItemLayout::ItXs(12))>
+                    <p>{match &form_page.signed_in {
+                        Some(sign_in) => format!(
+                            "Signed in as {} with a password of {} characters{}",
+                            sign_in.email,
+                            sign_in.password.len(),
+                            if sign_in.remember { ", remembered" } else { "" }
+                        ),
+                        None => format!("Valid: {}", form_page.form_model.is_valid()),
+                    }}</p>
+                </Item>
+            </Container>
+        </Form<SignIn>>
+    }
+}
+
 fn get_form_group(form_page: &FormPage) -> Html {
     html! {
         <Container wrap=Wrap::Wrap direction=Direction::Row>
@@ -487,3 +733,88 @@ fn get_textarea(form_page: &FormPage) -> Html {
         </Container>
     }
 }
+
+fn get_checkboxes(form_page: &FormPage) -> Html {
+    let total_toppings = form_page.toppings.len();
+
+    html! {
+        <Container wrap=Wrap::Wrap direction=Direction::Row>
+            <Item layouts=vec!(ItemLayout::ItL(6), ItemLayout::ItXs(12))>
+                <FormGroup orientation=Orientation::Vertical>
+                    <FormCheckbox
+                        label="All toppings"
+                        checkbox_palette=Palette::Primary
+                        checked={total_toppings == TOPPINGS.len()}
+                        indeterminate={total_toppings > 0 && total_toppings < TOPPINGS.len()}
+                        onchange_signal=form_page.link.callback(|(_, checked)| Msg::AllToppings(checked))
+                    />
+                    {TOPPINGS.iter().map(|topping| {
+                        let checked = form_page.toppings.contains(&topping.to_string());
+
+                        html! {
+                            <FormCheckbox
+                                label=topping.to_string()
+                                name="toppings"
+                                value=topping.to_string()
+                                checkbox_palette=Palette::Primary
+                                checkbox_size=Size::Small
+                                checked=checked
+                                onchange_signal=form_page.link.callback(|(topping, checked)| Msg::Topping(topping, checked))
+                            />
+                        }
+                    }).collect::<Html>()}
+                    <div>{format!("Toppings: {}", form_page.toppings.join(", "))}</div>
+                </FormGroup>
+            </Item>
+            <Item layouts=vec!(ItemLayout::ItL(6), ItemLayout::ItXs(12))>
+                <FormGroup orientation=Orientation::Vertical>
+                    <FormCheckbox label="Big success" checkbox_palette=Palette::Success checkbox_size=Size::Big checked=true />
+                    <FormCheckbox label="Disabled" disabled=true />
+                </FormGroup>
+            </Item>
+        </Container>
+    }
+}
+
+fn get_radio_groups(form_page: &FormPage) -> Html {
+    html! {
+        <Container wrap=Wrap::Wrap direction=Direction::Row>
+            <Item layouts=vec!(ItemLayout::ItL(6), ItemLayout::ItXs(12))>
+                <FormGroup orientation=Orientation::Vertical>
+                    <FormLabel text="Vertical plan:"/>
+                    <FormRadioGroup<String>
+                        name="plan"
+                        radio_palette=Palette::Primary
+                        options=vec![
+                            RadioOption::new("free".to_string(), "Free"),
+                            RadioOption::new("pro".to_string(), "Pro"),
+                            RadioOption::disabled("enterprise".to_string(), "Enterprise"),
+                            RadioOption::new("team".to_string(), "Team"),
+                        ]
+                        selected=form_page.plan.clone()
+                        onchange_signal=form_page.link.callback(Msg::Plan)
+                    />
+                    <div>{format!("Plan: {}", form_page.plan.clone().unwrap_or_default())}</div>
+                </FormGroup>
+            </Item>
+            <Item layouts=vec!(ItemLayout::ItL(6), ItemLayout::ItXs(12))>
+                <FormGroup orientation=Orientation::Vertical>
+                    <FormLabel text="Horizontal plan:"/>
+                    <FormRadioGroup<String>
+                        name="plan-horizontal"
+                        radio_palette=Palette::Success
+                        radio_size=Size::Big
+                        orientation=Orientation::Horizontal
+                        options=vec![
+                            RadioOption::new("free".to_string(), "Free"),
+                            RadioOption::new("pro".to_string(), "Pro"),
+                            RadioOption::new("team".to_string(), "Team"),
+                        ]
+                        selected=form_page.plan.clone()
+                        onchange_signal=form_page.link.callback(Msg::Plan)
+                    />
+                </FormGroup>
+            </Item>
+        </Container>
+    }
+}
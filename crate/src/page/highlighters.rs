@@ -9,6 +9,61 @@ pub fn button_code() -> String {
         .to_string()
 }
 
+pub fn button_icons_code() -> String {
+    "<Button
+    onclick_signal=link.callback(|_| Msg::Download)
+    icon_left=Some(ButtonIcon::Controller(ControllerIcon::ArrowDownCircle))
+>{\"Download\"}</Button>
+<Button
+    onclick_signal=link.callback(|_| Msg::Edit)
+    icon_only=Some(IconOnly::new(ButtonIcon::Editing(EditingIcon::Edit), \"Edit\"))
+/>"
+        .to_string()
+}
+
+pub fn button_toggle_link_code() -> String {
+    "<Button
+    toggle=true
+    pressed=self.bold
+    ontoggle_signal=link.callback(Msg::ToggleBold)
+>{\"Bold\"}</Button>
+<Button
+    href=\"https://github.com/spielrs/yew_styles\"
+    target=\"_blank\"
+>{\"Repository\"}</Button>
+<Button button_type=ButtonType::Submit disabled=true>{\"Send\"}</Button>"
+        .to_string()
+}
+
+pub fn button_group_code() -> String {
+    "<ButtonGroup<View>
+    button_palette=Palette::Primary
+    button_size=Size::Small
+    selection=Selection::Single
+    values=vec![View::List, View::Grid, View::Map]
+    selected=vec![self.view.clone()]
+    onchange_signal=self.link.callback(Msg::ChangeView)
+>
+    <Button>{\"List\"}</Button>
+    <Button>{\"Grid\"}</Button>
+    <Button>{\"Map\"}</Button>
+</ButtonGroup<View>>"
+        .to_string()
+}
+
+pub fn split_button_code() -> String {
+    "<SplitButton
+    main_content=html! {<span>{\"Save\"}</span>}
+    button_palette=Palette::Primary
+    button_style=Style::Regular
+    onclick_signal=self.link.callback(|_| Msg::Save)
+>
+    <DropdownItem onclick_signal=self.link.callback(|_| Msg::SaveAs)>{\"Save as...\"}</DropdownItem>
+    <DropdownItem onclick_signal=self.link.callback(|_| Msg::SaveAndClose)>{\"Save and close\"}</DropdownItem>
+</SplitButton>"
+        .to_string()
+}
+
 pub fn container_code() -> String {
     "<Container direction=Direction::Row wrap=Wrap::Wrap class_name=\"align-item\">
     <Item name=\"align\" index=0 layouts=vec!(ItemLayout::ItXs(4)) align_self=AlignSelf::FlexStart>
@@ -24,6 +79,40 @@ pub fn container_code() -> String {
         .to_string()
 }
 
+pub fn grid_code() -> String {
+    "<Grid
+    columns=vec![
+        Responsive::Xs(GridTemplate::Repeat(1, GridTrack::Fr(1.0))),
+        Responsive::M(GridTemplate::Repeat(3, GridTrack::Fr(1.0))),
+    ]
+    auto_flow=AutoFlow::Row
+>
+    <GridItem column=vec![Responsive::M(GridSpan::Span(2))]>
+        <h3>{\"chart\"}</h3>
+    </GridItem>
+    <GridItem>
+        <h3>{\"summary\"}</h3>
+    </GridItem>
+</Grid>"
+        .to_string()
+}
+
+pub fn split_pane_code() -> String {
+    "<SplitPane
+    direction=SplitDirection::Horizontal
+    panes=vec![
+        PaneOptions { min_size: 20.0, collapsible: true, ..PaneOptions::new(30.0) },
+        PaneOptions::new(70.0),
+    ]
+    storage_key=Some(\"editor-panes\".to_string())
+    onresize_signal=link.callback(Msg::Resized)
+>
+    <div>{\"files\"}</div>
+    <div>{\"editor\"}</div>
+</SplitPane>"
+        .to_string()
+}
+
 pub fn navbar_code() -> String {
     "<Navbar
     fixed=Fixed::None
@@ -137,6 +226,108 @@ pub fn textarea_code() -> String {
     .to_string()
 }
 
+pub fn checkbox_code() -> String {
+    "<FormCheckbox
+    label=\"All toppings\"
+    checkbox_palette=Palette::Primary
+    checked={total_toppings == TOPPINGS.len()}
+    indeterminate={total_toppings > 0 && total_toppings < TOPPINGS.len()}
+    onchange_signal=form_page.link.callback(|(_, checked)| Msg::AllToppings(checked))
+/>
+<FormCheckbox
+    label=\"Cheese\"
+    name=\"toppings\"
+    value=\"Cheese\"
+    checkbox_palette=Palette::Primary
+    checkbox_size=Size::Small
+    checked=form_page.toppings.contains(&\"Cheese\".to_string())
+    onchange_signal=form_page.link.callback(|(topping, checked)| Msg::Topping(topping, checked))
+/>"
+    .to_string()
+}
+
+pub fn radio_group_code() -> String {
+    "<FormRadioGroup<String>
+    name=\"plan\"
+    radio_palette=Palette::Primary
+    options=vec![
+        RadioOption::new(\"free\".to_string(), \"Free\"),
+        RadioOption::new(\"pro\".to_string(), \"Pro\"),
+        RadioOption::disabled(\"enterprise\".to_string(), \"Enterprise\"),
+        RadioOption::new(\"team\".to_string(), \"Team\"),
+    ]
+    selected=form_page.plan.clone()
+    onchange_signal=form_page.link.callback(Msg::Plan)
+/>"
+    .to_string()
+}
+
+pub fn form_model_code() -> String {
+    "let form_model = FormModel::new()
+    .field(\"email\", \"\", vec![
+        Validator::Required(\"Email is required\".to_string()),
+        Validator::Email(\"Email is not valid\".to_string()),
+    ])
+    .field(\"password\", \"\", vec![
+        Validator::MinLength(8, \"At least 8 characters\".to_string()),
+        Validator::custom(|value| {
+            if value.chars().any(|c| c.is_numeric()) {
+                None
+            } else {
+                Some(\"At least one number\".to_string())
+            }
+        }),
+    ]);
+
+html! {
+    <Form
+        form_model=Some(self.form_model.clone())
+        onchange_model_signal=self.link.callback(Msg::ChangeModel)
+        onsubmit_signal=self.link.callback(|_| Msg::SignIn)
+    >
+        <FormInput
+            name=\"email\"
+            input_type=InputType::Email
+            form_model=Some(self.form_model.clone())
+        />
+        <FormInput
+            name=\"password\"
+            input_type=InputType::Password
+            form_model=Some(self.form_model.clone())
+        />
+        <FormSubmit value=\"Sign in\" />
+    </Form>
+}"
+    .to_string()
+}
+
+pub fn form_values_code() -> String {
+    "#[derive(Clone, PartialEq, Deserialize)]
+pub struct SignIn {
+    email: String,
+    password: String,
+    #[serde(default)]
+    remember: bool,
+}
+
+html! {
+    <Form<SignIn>
+        onsubmit_values_signal=self.link.callback(|result: Result<SignIn, Vec<FieldError>>| {
+            match result {
+                Ok(sign_in) => Msg::SignIn(sign_in),
+                Err(errors) => Msg::ShowErrors(errors),
+            }
+        })
+    >
+        <FormInput name=\"email\" input_type=InputType::Email />
+        <FormInput name=\"password\" input_type=InputType::Password />
+        <FormInput name=\"remember\" input_type=InputType::Checkbox />
+        <FormSubmit value=\"Sign in\" />
+    </Form<SignIn>>
+}"
+    .to_string()
+}
+
 pub fn basic_form_code() -> String {
     "<Container wrap=Wrap::Wrap direction=Direction::Row>
     <Item layouts=vec!(ItemLayout::ItXs(12))>
@@ -474,3 +665,59 @@ pub fn get_stylist() -> String {
 />"
     .to_string()
 }
+
+pub fn get_app_shell() -> String {
+    "<AppShell
+    navbar_palette=Palette::Primary
+    navbar_style=Style::Regular
+    branch=html! {<img src=\"/assets/spielrs_logo.png\"></img>}
+    header=html! {
+        <NavbarContainer>
+            <NavbarItem><span>{\"Profile\"}</span></NavbarItem>
+        </NavbarContainer>
+    }
+    sidebar=html! {<ul><li>{\"Dashboard\"}</li></ul>}
+    footer=html! {<span>{\"Spielrs\"}</span>}
+>
+    <h1>{\"Dashboard\"}</h1>
+</AppShell>"
+        .to_string()
+}
+
+pub fn get_theme() -> String {
+    "let mut theme = Theme::default();
+theme.primary.regular = StyleColors::new(\"#1d3557\", \"#fff\", None);
+
+html! {
+    <ThemeProvider theme=theme>
+        <Button
+            button_palette=Palette::Primary
+            button_style=Style::Regular
+        >{\"Themed button\"}</Button>
+    </ThemeProvider>
+}"
+    .to_string()
+}
+
+pub fn get_custom_palette() -> String {
+    "let brand = Palette::Custom(CustomPalette::new(\"brand\", \"#2a9d8f\", \"#fff\", None));
+
+html! {
+    <Button
+        button_palette=brand
+        button_style=Style::Outline
+    >{\"Brand\"}</Button>
+}"
+    .to_string()
+}
+
+pub fn get_theme_tokens() -> String {
+    "let theme = Theme::default();
+
+// :root { --ys-font-size-big: 26px; ... --ys-primary-regular-bg: #654016; ... }
+let root_css = theme.get_root_css();
+
+// { \"ys-font-size-big\": \"26px\", ... \"ys-primary-regular-bg\": \"#654016\", ... }
+let tokens = serde_json::to_string(&theme.get_tokens()).unwrap();"
+        .to_string()
+}
@@ -67,6 +67,12 @@ impl Component for HomePage {
                         <li>{"Ready to import and use in your project \u{1F680}"}</li>
                     </ol>
 
+                    <h3>{"Without npm"}</h3>
+                    <p>{"Enabling the feature "}<code>{"embedded-styles"}</code>{" the base css of each component is injected with stylist,
+                    then the sass module and the main.css import are not needed: "}<br/>
+                        <code>{"yew_styles = {version=\"0.11\", features=[\"layouts\",\"button\",\"embedded-styles\"]}"}</code>
+                    </p>
+
                     <p>{"In the left side there is a list of links where each one access to a correspondent component documentation,
                     there, shows how to use it."}</p>
                 </Item>
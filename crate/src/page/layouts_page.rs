@@ -1,10 +1,15 @@
-use super::highlighters::container_code;
+use super::highlighters::{container_code, grid_code, split_pane_code};
 use yew::prelude::*;
 use yew_prism::Prism;
 use yew_styles::layouts::{
     container::{AlignContent, AlignItems, Container, Direction, JustifyContent, Mode, Wrap},
+    grid::{Grid, GridAreas, GridTemplate, GridTrack},
+    grid_item::{GridItem, GridSpan},
     item::{AlignSelf, Item, ItemLayout},
+    split_pane::{PaneOptions, SplitDirection, SplitPane},
 };
+use yew_styles::styles::Size;
+use yew_styles::theme::Responsive;
 
 pub struct LayoutsPage;
 
@@ -42,12 +47,22 @@ impl Component for LayoutsPage {
 
                 <h2>{"Container properties"}</h2>
                 <ul>
-                    <li><b>{"direction: "}</b>{"which direction are placing the items. Options included in "}<code>{"Direction"}</code>{". Required."}</li>
+                    <li><b>{"direction: "}</b>{"which direction are placing the items. Options included in "}<code>{"Direction"}</code>{". Default "}<code>{"Row"}</code>{"."}</li>
                     <li><b>{"wrap: "}</b>{"set a wrap for the items. Options included in "}<code>{"Wrap"}</code>{". Default "}<code>{"Wrap"}</code>{"."}</li>
                     <li><b>{"justify_content: "}</b>{"set how will be justified the content. Options included in "}<code>{"JustifyContent"}</code>{". Default "}<code>{"FlexStart(No Mode)"}</code>{"."}</li>
                     <li><b>{"align_content: "}</b>{"set how will be aligned the content. Options included in "}<code>{"AlignContent"}</code>{". Default "}<code>{"Stretch(NoMode)"}</code>{"."}</li>
                     <li><b>{"align_items: "}</b>{"set how will be aligned the items. Options included in "}<code>{"AlignItems"}</code>{". Default "}<code>{"Stretch(NoMode)"}</code>{"."}</li>
+                    <li><b>{"responsive_direction: "}</b>{"direction of the items from the breakpoint of each screen, it overrides "}<code>{"direction"}</code>{". The value is a vector "}<code>{"Vec<Responsive<Direction>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"responsive_wrap: "}</b>{"wrap of the items from the breakpoint of each screen, it overrides "}<code>{"wrap"}</code>{". The value is a vector "}<code>{"Vec<Responsive<Wrap>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"responsive_justify_content: "}</b>{"justify content from the breakpoint of each screen, it overrides "}<code>{"justify_content"}</code>{". The value is a vector "}<code>{"Vec<Responsive<JustifyContent>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"responsive_align_items: "}</b>{"align items from the breakpoint of each screen, it overrides "}<code>{"align_items"}</code>{". The value is a vector "}<code>{"Vec<Responsive<AlignItems>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"gap: "}</b>{"space between the rows and the columns in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"row_gap: "}</b>{"space between the rows in each screen. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"column_gap: "}</b>{"space between the columns in each screen. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"space inside of the component in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"margin: "}</b>{"space around the component in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
                     <li><b>{"mode: "}</b>{"safe postion handler which is additional option for justify_content, align_content and align_items. Options included in "}<code>{"Mode"}</code>{". Default "}<code>{"NoMode"}</code>{"."}</li>
+                    <li><b>{"breakpoints: "}</b>{"min width of each screen for the responsive properties. Default "}<code>{"Breakpoints::default()"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
@@ -59,7 +74,15 @@ impl Component for LayoutsPage {
                 <ul>
                     <li><b>{"layouts: "}</b>{"percent of the layout that will take the item. The value is a vector "}<code>{"Vec<ItemLayout>"}</code>{". Required"}</li>
                     <li><b>{"align_self: "}</b>{"align the item itself. Options include in "}<code>{"AlignSelf"}</code>{". Default "}<code>{"Auto"}</code></li>
+                    <li><b>{"order: "}</b>{"order of the item in the container in each screen. The value is a vector "}<code>{"Vec<Responsive<i16>>"}</code>{". Default "}<code>{"vec![]"}</code></li>
+                    <li><b>{"offset: "}</b>{"number of columns, of the 12 of the layout, skipped before the item in each screen. The value is a vector "}<code>{"Vec<Responsive<i8>>"}</code>{". Default "}<code>{"vec![]"}</code></li>
+                    <li><b>{"flex_grow: "}</b>{"how much the item grows with the free space in each screen. The value is a vector "}<code>{"Vec<Responsive<f64>>"}</code>{". Default "}<code>{"vec![]"}</code></li>
+                    <li><b>{"flex_shrink: "}</b>{"how much the item shrinks when there is not enough space in each screen. The value is a vector "}<code>{"Vec<Responsive<f64>>"}</code>{". Default "}<code>{"vec![]"}</code></li>
+                    <li><b>{"flex_basis: "}</b>{"initial size of the item in each screen, it overrides the size of layouts. Options included in "}<code>{"FlexBasis"}</code>{". Default "}<code>{"vec![]"}</code></li>
                     <li><b>{"onclick_signal: "}</b>{"click event for the item. Default "}<code>{"noop()"}</code></li>
+                    <li><b>{"padding: "}</b>{"space inside of the component in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"margin: "}</b>{"space around the component in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"breakpoints: "}</b>{"min width of each screen for the responsive properties. Default "}<code>{"Breakpoints::default()"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
@@ -97,6 +120,26 @@ impl Component for LayoutsPage {
                 <Container direction=Direction::ColumnReverse wrap=Wrap::Wrap>
                     {(1..5).map(|x| LayoutsPageModel.get_items(x)).collect::<Html>()}
                 </Container>
+                <h3>{"Column direction in mobile and row direction from medium screens"}</h3>
+                <Container
+                    responsive_direction=vec![Responsive::Xs(Direction::Column), Responsive::M(Direction::Row)]
+                    responsive_justify_content=vec![Responsive::M(JustifyContent::SpaceBetween(Mode::NoMode))]
+                >
+                    {(1..4).map(|x| html! {
+                        <Item layouts=vec!(ItemLayout::ItXs(12), ItemLayout::ItM(3))>
+                            <h3>{x}</h3>
+                        </Item>
+                    }).collect::<Html>()}
+                </Container>
+                <h3>{"Order and offset"}</h3>
+                <Container>
+                    <Item layouts=vec!(ItemLayout::ItXs(6), ItemLayout::ItM(3)) order=vec![Responsive::Xs(2), Responsive::M(0)]>
+                        <h3>{"1"}</h3>
+                    </Item>
+                    <Item layouts=vec!(ItemLayout::ItXs(6), ItemLayout::ItM(3)) offset=vec![Responsive::M(6)]>
+                        <h3>{"2"}</h3>
+                    </Item>
+                </Container>
                 <h3>{"Combination of column and row direction"}</h3>
                 <Container direction=Direction::Row wrap=Wrap::Wrap>
                     <Item
@@ -205,6 +248,131 @@ impl Component for LayoutsPage {
                 <p>{"To know about more options please visit "}
                     <a href="https://developer.mozilla.org/en-US/docs/Web/CSS/align-self" target="_bank">{"Align Self"}</a>
                 </p>
+
+                <h2>{"Grid"}</h2>
+                <p>{"For two dimensional layouts use "}<code>{"Grid"}</code>{" and "}<code>{"GridItem"}</code>{". Each property takes a vector of "}<code>{"Responsive"}</code>{" values which are applied from the breakpoint of its screen."}</p>
+                <Prism
+                    code=grid_code()
+                    language="rust"
+                />
+
+                <h2>{"Grid properties"}</h2>
+                <ul>
+                    <li><b>{"columns: "}</b>{"tracks of the columns in each screen. Options included in "}<code>{"GridTemplate"}</code>{" and "}<code>{"GridTrack"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"rows: "}</b>{"tracks of the rows in each screen. Options included in "}<code>{"GridTemplate"}</code>{" and "}<code>{"GridTrack"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"areas: "}</b>{"named areas of the grid in each screen. Options included in "}<code>{"GridAreas"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"auto_flow: "}</b>{"set how will be placed the items without position. Options included in "}<code>{"AutoFlow"}</code>{". Default "}<code>{"Row"}</code>{"."}</li>
+                    <li><b>{"gap: "}</b>{"space between the rows and the columns in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"row_gap: "}</b>{"space between the rows in each screen. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"column_gap: "}</b>{"space between the columns in each screen. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"padding: "}</b>{"space inside of the component in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"margin: "}</b>{"space around the component in each screen from the spacings of the theme. The value is a vector "}<code>{"Vec<Responsive<Size>>"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"breakpoints: "}</b>{"min width of each screen for the responsive properties. Default "}<code>{"Breakpoints::default()"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h2>{"Grid item properties"}</h2>
+                <ul>
+                    <li><b>{"column: "}</b>{"columns that will take the item in each screen. Options included in "}<code>{"GridSpan"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"row: "}</b>{"rows that will take the item in each screen. Options included in "}<code>{"GridSpan"}</code>{". Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"area: "}</b>{"named area where is placed the item in each screen. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"onclick_signal: "}</b>{"click event for the item. Default "}<code>{"noop()"}</code></li>
+                    <li><b>{"breakpoints: "}</b>{"min width of each screen for the responsive properties. Default "}<code>{"Breakpoints::default()"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h3>{"Dashboard"}</h3>
+                <Grid
+                    columns=vec![
+                        Responsive::Xs(GridTemplate::Repeat(1, GridTrack::Fr(1.0))),
+                        Responsive::M(GridTemplate::Tracks(vec![
+                            GridTrack::MinMax(Box::new(GridTrack::Px(150.0)), Box::new(GridTrack::Fr(1.0))),
+                            GridTrack::Fr(3.0),
+                        ])),
+                    ]
+                    areas=vec![
+                        Responsive::Xs(GridAreas::Rows(vec![
+                            "header".to_string(),
+                            "aside".to_string(),
+                            "main".to_string(),
+                        ])),
+                        Responsive::M(GridAreas::Rows(vec![
+                            "header header".to_string(),
+                            "aside main".to_string(),
+                        ])),
+                    ]
+                >
+                    <GridItem area=vec![Responsive::Xs("header".to_string())]>
+                        <h3>{"header"}</h3>
+                    </GridItem>
+                    <GridItem area=vec![Responsive::Xs("aside".to_string())]>
+                        <h3>{"aside"}</h3>
+                    </GridItem>
+                    <GridItem area=vec![Responsive::Xs("main".to_string())]>
+                        <h3>{"main"}</h3>
+                    </GridItem>
+                </Grid>
+                <h3>{"Span"}</h3>
+                <Grid
+                    columns=vec![Responsive::Xs(GridTemplate::Repeat(4, GridTrack::Fr(1.0)))]
+                    gap=vec![Responsive::Xs(Size::Small), Responsive::M(Size::Big)]
+                >
+                    <GridItem column=vec![Responsive::Xs(GridSpan::Span(4)), Responsive::M(GridSpan::Span(2))]>
+                        <h3>{"1"}</h3>
+                    </GridItem>
+                    <GridItem column=vec![Responsive::Xs(GridSpan::Span(2)), Responsive::M(GridSpan::Span(1))]>
+                        <h3>{"2"}</h3>
+                    </GridItem>
+                    <GridItem column=vec![Responsive::Xs(GridSpan::Span(2)), Responsive::M(GridSpan::Span(1))]>
+                        <h3>{"3"}</h3>
+                    </GridItem>
+                </Grid>
+                <p>{"To know about more options please visit "}
+                    <a href="https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Grid_Layout" target="_bank">{"CSS Grid Layout"}</a>
+                </p>
+
+                <h2>{"Split Pane"}</h2>
+                <p>{"Each child of "}<code>{"SplitPane"}</code>{" is a pane. The dividers between them can be dragged with the mouse, touch or pen or moved with the arrow keys."}</p>
+                <Prism
+                    code=split_pane_code()
+                    language="rust"
+                />
+
+                <h2>{"Split pane properties"}</h2>
+                <ul>
+                    <li><b>{"direction: "}</b>{"where are placed the panes. Options included in "}<code>{"SplitDirection"}</code>{". Default "}<code>{"Horizontal"}</code>{"."}</li>
+                    <li><b>{"panes: "}</b>{"size, min size, max size in percent and if it collapses to the edge, for each pane. The value is a vector "}<code>{"Vec<PaneOptions>"}</code>{". Without it the panes take the same size. Default "}<code>{"vec![]"}</code>{"."}</li>
+                    <li><b>{"storage_key: "}</b>{"key of the localStorage where the sizes are saved after each resize. Default "}<code>{"None"}</code>{"."}</li>
+                    <li><b>{"onresize_signal: "}</b>{"signal with the new sizes in percent after each resize. Default "}<code>{"noop()"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id"}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles"}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h3>{"Horizontal and vertical panes"}</h3>
+                <SplitPane
+                    class_name="split-pane-example"
+                    panes=vec![
+                        PaneOptions { min_size: 20.0, collapsible: true, ..PaneOptions::new(30.0) },
+                        PaneOptions::new(70.0),
+                    ]
+                >
+                    <h3>{"files"}</h3>
+                    <SplitPane direction=SplitDirection::Vertical>
+                        <h3>{"editor"}</h3>
+                        <h3>{"terminal"}</h3>
+                    </SplitPane>
+                </SplitPane>
             </>
         }
     }
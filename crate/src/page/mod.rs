@@ -1,5 +1,7 @@
+pub mod app_shell_page;
 pub mod assets_page;
 pub mod basic_form_page;
+pub mod button_group_page;
 pub mod button_page;
 pub mod card_page;
 pub mod carousel_page;
@@ -11,12 +13,16 @@ pub mod layouts_page;
 pub mod modal_page;
 pub mod navbar_page;
 pub mod spinner_page;
+pub mod split_button_page;
 pub mod stylist_page;
 pub mod text_page;
+pub mod theme_page;
 pub mod tooltip_page;
 
+pub use self::app_shell_page::AppShellPage;
 pub use self::assets_page::AssetsPage;
 pub use self::basic_form_page::BasicFormPage;
+pub use self::button_group_page::ButtonGroupPage;
 pub use self::button_page::ButtonPage;
 pub use self::card_page::CardPage;
 pub use self::carousel_page::CarouselPage;
@@ -27,6 +33,8 @@ pub use self::layouts_page::LayoutsPage;
 pub use self::modal_page::ModalPage;
 pub use self::navbar_page::NavbarPage;
 pub use self::spinner_page::SpinnerPage;
+pub use self::split_button_page::SplitButtonPage;
 pub use self::stylist_page::StylistPage;
 pub use self::text_page::TextPage;
+pub use self::theme_page::ThemePage;
 pub use self::tooltip_page::TooltipPage;
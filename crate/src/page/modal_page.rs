@@ -91,7 +91,7 @@ impl Component for ModalPage {
 
                 <h2>{"Properties"}</h2>
                 <ul>
-                    <li><b>{"modal_size: "}</b>{"three diffent modal standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"modal_size: "}</b>{"five diffent modal standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"modal_palette: "}</b>{"type modal background style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
                     <li><b>{"header: "}</b>{"header of the modal. Required"}</li>
                     <li><b>{"header_palette: "}</b>{"type modal header style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
@@ -210,7 +210,13 @@ impl Component for ModalPage {
 }
 
 fn get_modal_sizes(show_modal: Vec<bool>, link: ComponentLink<ModalPage>) -> Html {
-    let sizes: Vec<Size> = vec![Size::Small, Size::Medium, Size::Big];
+    let sizes: Vec<Size> = vec![
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Big,
+        Size::XLarge,
+    ];
 
     sizes
         .into_iter()
@@ -126,6 +126,7 @@ impl Component for NavbarPage {
                     <li><b>{"hide_navbar_items_mobile: "}</b>{"Hide Navbar items in mobile. Default "}<code>{"false"}</code></li>
                     <li><b>{"fixed: "}</b>{"the location of the navbar which is fixed .Options included in "}<code>{"Fixed"}</code>{". Default "}<code>{"Top"}</code>{"."}</li>
                     <li><b>{"branch: "}</b>{"vnode embedded in the beginning of the navbar, useful to include a branch logo. Optional"}</li>
+                    <li><b>{"breakpoints: "}</b>{"the navbar collapses in a menu in screens smaller than the "}<code>{"l"}</code>{" breakpoint. Default "}<code>{"Breakpoints::default()"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id"}</li>
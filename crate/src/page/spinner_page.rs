@@ -45,7 +45,7 @@ impl Component for SpinnerPage {
                 <ul>
                     <li><b>{"spinner_type: "}</b>{"spinner type. Options included in "}<code>{"SpinnerType"}</code>{". Default "}<code>{"Circle"}</code>{"."}</li>
                     <li><b>{"spinner_palette: "}</b>{"type spinner palette. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
-                    <li><b>{"spinner_size: "}</b>{"three diffent spinner standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"spinner_size: "}</b>{"five diffent spinner standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
                     <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
                     <li><b>{"id: "}</b>{"general property to add custom id."}</li>
@@ -77,7 +77,13 @@ impl Component for SpinnerPage {
 }
 
 fn get_sizes() -> Html {
-    let sizes: Vec<Size> = vec![Size::Small, Size::Medium, Size::Big];
+    let sizes: Vec<Size> = vec![
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Big,
+        Size::XLarge,
+    ];
 
     sizes
         .into_iter()
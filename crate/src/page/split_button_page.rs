@@ -0,0 +1,97 @@
+use super::highlighters::split_button_code;
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::{
+    dropdown::DropdownItem,
+    split_button::SplitButton,
+    styles::{Palette, Size, Style},
+};
+
+pub struct SplitButtonPage {
+    link: ComponentLink<Self>,
+    action: String,
+}
+
+pub enum Msg {
+    Action(String),
+}
+
+impl Component for SplitButtonPage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        SplitButtonPage {
+            link,
+            action: String::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Action(action) => {
+                self.action = action;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Split Button Component"}</h1>
+
+                <h2>{"Features required"}</h2>
+                <span><code>{"split_button"}</code></span>
+
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=split_button_code()
+                    language="rust"
+                />
+
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"main_content: "}</b>{"content of the main button. Required."}</li>
+                    <li><b>{"button_palette: "}</b>{"type palette of the buttons and the menu. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
+                    <li><b>{"button_style: "}</b>{"style of the buttons and the menu. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{"."}</li>
+                    <li><b>{"button_size: "}</b>{"size of the buttons. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"menu_label: "}</b>{"accessible label of the caret that opens the menu. Default "}<code>{"More actions"}</code>{"."}</li>
+                    <li><b>{"disabled: "}</b>{"whether the buttons are disabled. Default "}<code>{"false"}</code>{"."}</li>
+                    <li><b>{"onclick_signal: "}</b>{"click event for the main button. Each "}<code>{"DropdownItem"}</code>{" has its own "}<code>{"onclick_signal"}</code>{". Default "}<code>{"noop()"}</code>{"."}</li>
+                    <li><b>{"ontoggle_signal: "}</b>{"signal when the menu is opened or closed with the new state. Default "}<code>{"noop()"}</code>{"."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+
+                <h2>{"Visual examples"}</h2>
+                <SplitButton
+                    main_content=html! {<span>{"Save"}</span>}
+                    button_palette=Palette::Primary
+                    onclick_signal=self.link.callback(|_| Msg::Action("Save".to_string()))
+                >
+                    <DropdownItem onclick_signal=self.link.callback(|_| Msg::Action("Save as".to_string()))>{"Save as..."}</DropdownItem>
+                    <DropdownItem onclick_signal=self.link.callback(|_| Msg::Action("Save and close".to_string()))>{"Save and close"}</DropdownItem>
+                </SplitButton>
+                <SplitButton
+                    main_content=html! {<span>{"Merge"}</span>}
+                    button_palette=Palette::Success
+                    button_style=Style::Outline
+                    button_size=Size::Small
+                    onclick_signal=self.link.callback(|_| Msg::Action("Merge".to_string()))
+                >
+                    <DropdownItem onclick_signal=self.link.callback(|_| Msg::Action("Squash and merge".to_string()))>{"Squash and merge"}</DropdownItem>
+                    <DropdownItem onclick_signal=self.link.callback(|_| Msg::Action("Rebase and merge".to_string()))>{"Rebase and merge"}</DropdownItem>
+                </SplitButton>
+                <p>{format!("Action: {}", self.action)}</p>
+            </>
+        }
+    }
+}
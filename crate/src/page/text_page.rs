@@ -129,7 +129,7 @@ impl Component for TextPage {
                     <li><b>{"html_text: "}</b>{"text in html to show (if "}<code>{"plain_text"}</code>{" is set "}<code>{"html_text"}</code>{" will be ignored). Required."}</li>
                     <li><b>{"text_type: "}</b>{"the text type. Options included in "}<code>{"TextType"}</code>{". Required."}</li>
                     <li><b>{"text_palette: "}</b>{"type text purpose style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{". Only alert and tag types"}</li>
-                    <li><b>{"text_size: "}</b>{"three diffent text standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{". Not for title type"}</li>
+                    <li><b>{"text_size: "}</b>{"five diffent text standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{". Not for title type"}</li>
                     <li><b>{"text_style: "}</b>{"text styles. Options included in "}<code>{"Style"}</code>{". Default "}<code>{"Regular"}</code>{". Only alert and tag types"}</li>
                     <li><b>{"removable: "}</b>{"if the tag can be deleted. Default "}<code>{"false"}</code>{". Only for tag text type."}</li>
                     <li><b>{"onclick_signal: "}</b>{"click event for text. Only for tag type."}</li>
@@ -246,7 +246,14 @@ fn get_headers() -> Html {
 }
 
 fn get_text(text_type: TextType, words: usize, layout_size: i8) -> Html {
-    let styles = vec![Style::Regular, Style::Outline, Style::Light];
+    let styles = vec![
+        Style::Regular,
+        Style::Outline,
+        Style::Light,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ];
     let palette = vec![Palette::Success, Palette::Warning, Palette::Danger];
     let mut index = 0;
 
@@ -280,7 +287,14 @@ fn get_text(text_type: TextType, words: usize, layout_size: i8) -> Html {
 }
 
 fn get_draggable_tags(link: ComponentLink<TextPage>) -> Html {
-    let styles = vec![Style::Regular, Style::Outline, Style::Light];
+    let styles = vec![
+        Style::Regular,
+        Style::Outline,
+        Style::Light,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ];
     let palette = vec![Palette::Success, Palette::Warning, Palette::Danger];
     let mut index = 0;
 
@@ -0,0 +1,155 @@
+use super::highlighters::{get_custom_palette, get_theme, get_theme_tokens};
+use yew::prelude::*;
+use yew_prism::Prism;
+use yew_styles::button::Button;
+use yew_styles::styles::{CustomPalette, Palette, Style};
+use yew_styles::theme::{
+    get_color_mode, get_stored_color_mode, ColorMode, StyleColors, Theme, ThemeProvider,
+};
+
+pub struct ThemePage {
+    link: ComponentLink<Self>,
+    theme: Theme,
+    custom_theme: bool,
+    color_mode: ColorMode,
+}
+
+pub enum Msg {
+    ChangeTheme,
+    ChangeColorMode,
+}
+
+impl Component for ThemePage {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            theme: Theme::default(),
+            custom_theme: false,
+            color_mode: get_stored_color_mode().unwrap_or(ColorMode::Auto),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeTheme => {
+                if self.custom_theme {
+                    self.theme = Theme::default();
+                } else {
+                    self.theme.primary.regular = StyleColors::new("#1d3557", "#fff", None);
+                    self.theme.primary.outline =
+                        StyleColors::new("#fff", "#1d3557", Some("#1d3557"));
+                    self.theme.primary.light = StyleColors::new("#a8dadc", "#1d3557", None);
+                }
+
+                self.custom_theme = !self.custom_theme;
+            }
+            Msg::ChangeColorMode => {
+                self.color_mode = match self.color_mode {
+                    ColorMode::Auto => ColorMode::Light,
+                    ColorMode::Light => ColorMode::Dark,
+                    ColorMode::Dark => ColorMode::Auto,
+                };
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <h1>{"Theme Provider Component"}</h1>
+                <h2>{"Features required"}</h2>
+                <span>{"none"}</span>
+                <h2>{"Code example"}</h2>
+                <Prism
+                    code=get_theme()
+                    language="rust"
+                />
+                <h2>{"Properties"}</h2>
+                <ul>
+                    <li><b>{"theme: "}</b>{"colors and font sizes applied to the components inside of the provider. It can be changed in runtime. Default "}<code>{"Theme::default()"}</code>{"."}</li>
+                    <li><b>{"dark_theme: "}</b>{"theme applied in dark mode. Default "}<code>{"Theme::dark()"}</code>{"."}</li>
+                    <li><b>{"color_mode: "}</b>{"force the color mode and save it in the localStorage. Options included in "}<code>{"ColorMode"}</code>{". Without it, takes the color mode saved or "}<code>{"ColorMode::Auto"}</code>{" which follows "}<code>{"prefers-color-scheme"}</code>{"."}</li>
+                    <li><b>{"dir: "}</b>{"direction of the text of the components inside, in right to left the components are mirrored. Options included in "}<code>{"TextDirection"}</code>{". Without it, inherits the direction."}</li>
+                    <li><b>{"key: "}</b>{"general property to add keys."}</li>
+                    <li><b>{"code_ref: "}</b>{"general property to get the ref of the component."}</li>
+                    <li><b>{"id: "}</b>{"general property to add custom id."}</li>
+                    <li><b>{"class_name: "}</b>{"general property to add custom class styles."}</li>
+                    <li><b>{"styles: "}</b>{"use stylist-rs to write styles in the component. Example: "}<code>{"css!(\"background-color: #918d94;\")"}</code></li>
+                </ul>
+                <h2>{"Visual example"}</h2>
+                <ThemeProvider theme=self.theme.clone() color_mode=Some(self.color_mode.clone())>
+                    <Button
+                        onclick_signal=self.link.callback(|_| Msg::ChangeColorMode)
+                        button_palette=Palette::Standard
+                        button_style=Style::Outline
+                    >{format!("Color mode: {}", get_color_mode(self.color_mode.clone()))}</Button>
+                    <Button
+                        onclick_signal=self.link.callback(|_| Msg::ChangeTheme)
+                        button_palette=Palette::Primary
+                        button_style=Style::Regular
+                    >{"Change theme"}</Button>
+                    <Button
+                        button_palette=Palette::Primary
+                        button_style=Style::Outline
+                    >{"Outline"}</Button>
+                    <Button
+                        button_palette=Palette::Primary
+                        button_style=Style::Light
+                    >{"Light"}</Button>
+                </ThemeProvider>
+                <h2>{"Design tokens"}</h2>
+                <p>{"The theme can be exported as a "}<code>{":root"}</code>{" block of css custom properties with "}<code>{"get_root_css"}</code>
+                {" or as a serializable map of tokens with "}<code>{"get_tokens"}</code>{", to use the same colors and sizes in pages without yew styles.
+                Overriding one of these css custom properties changes all the components that use it."}</p>
+                <Prism
+                    code=get_theme_tokens()
+                    language="rust"
+                />
+                <h2>{"Spacings"}</h2>
+                <p>{"The space used by the "}<code>{"gap"}</code>{", "}<code>{"padding"}</code>{" and "}<code>{"margin"}</code>{" properties of the layouts comes from "}<code>{"theme.spacings"}</code>{", one value for each "}<code>{"Size"}</code>{". "}<code>{"Size::Custom"}</code>{" multiplies the medium spacing."}</p>
+                <h2>{"Breakpoints"}</h2>
+                <p>{"The min width in pixels of each screen is configured with "}<code>{"Breakpoints"}</code>{". By default "}<code>{"s: 576, m: 768, l: 992, xl: 1200"}</code>{".
+                The layouts, the navbar and the app shell take them in their "}<code>{"breakpoints"}</code>{" property, like "}<code>{"breakpoints=Breakpoints { l: 1024, ..Breakpoints::default() }"}</code>{"."}</p>
+                <h2>{"Custom palette"}</h2>
+                <p>{"Besides the palettes included, it is possible to create a custom one with "}<code>{"Palette::Custom"}</code>{". The outline and light styles are created from its colors."}</p>
+                <Prism
+                    code=get_custom_palette()
+                    language="rust"
+                />
+                {get_custom_palette_buttons()}
+            </>
+        }
+    }
+}
+
+fn get_custom_palette_buttons() -> Html {
+    let brand = Palette::Custom(CustomPalette::new("brand", "#2a9d8f", "#fff", None));
+    let styles: Vec<Style> = vec![
+        Style::Regular,
+        Style::Outline,
+        Style::Light,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ];
+
+    styles
+        .into_iter()
+        .map(|style| {
+            html! {
+                <Button
+                    button_palette=brand.clone()
+                    button_style=style
+                >{"Brand"}</Button>
+            }
+        })
+        .collect::<Html>()
+}
@@ -42,7 +42,7 @@ impl Component for TooltipPage {
                 <ul>
                     <li><b>{"tooltip_style: "}</b>{"type tooltip style. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
                     <li><b>{"tooltip_palette: "}</b>{"type spinner palette. Options included in "}<code>{"Pallete"}</code>{". Default "}<code>{"Standard"}</code>{"."}</li>
-                    <li><b>{"tooltip_size: "}</b>{"three diffent tooltip standard sizes. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
+                    <li><b>{"tooltip_size: "}</b>{"five diffent tooltip standard sizes or a custom scale of the medium size. Options included in "}<code>{"Size"}</code>{". Default "}<code>{"Medium"}</code>{"."}</li>
                     <li><b>{"tooltip_position: "}</b>{"The postion where the tooltip will show over the content target. Options included in "}<code>{"Left"}</code><code>{" Right "}</code><code>{"Above "}</code><code>{"Below"}</code>{"."}</li>
                     <li><b>{"content: "}</b>{"The content that the tooltip will show"}</li>
                     <li><b>{"key: "}</b>{"general property to add keys."}</li>
@@ -59,7 +59,14 @@ impl Component for TooltipPage {
 }
 
 fn get_tooltip_styles() -> Html {
-    let styles: Vec<Style> = vec![Style::Regular, Style::Light, Style::Outline];
+    let styles: Vec<Style> = vec![
+        Style::Regular,
+        Style::Light,
+        Style::Outline,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ];
 
     styles
         .into_iter()
@@ -76,7 +83,13 @@ fn get_tooltip_styles() -> Html {
 }
 
 fn get_sizes(tooltip_style: Style, index_style: usize) -> Html {
-    let sizes: Vec<Size> = vec![Size::Small, Size::Medium, Size::Big];
+    let sizes: Vec<Size> = vec![
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Big,
+        Size::XLarge,
+    ];
 
     sizes
         .into_iter()
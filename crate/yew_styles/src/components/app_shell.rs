@@ -0,0 +1,434 @@
+use crate::embedded_styles::{mount_styles, APP_SHELL_STYLES};
+use crate::navbar::navbar_component::{Fixed, Navbar};
+use crate::styles::{Palette, Style};
+use crate::theme::{get_theme_color, Breakpoints, ThemeColor};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew::{utils, App};
+use yew_assets::ux_assets::{UxAssets, UxIcon};
+
+/// # App Shell component
+///
+/// Frame of the application with a header, a sidebar, the content and a footer.
+/// The header is a `Navbar`, by default with `Fixed::None`, it stays on the top while the content scrolls.
+/// In screens smaller than the `l` breakpoint the sidebar is an off-canvas drawer,
+/// in bigger screens the sidebar can be collapsed
+///
+/// ## Features required
+///
+/// app_shell
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::app_shell::AppShell;
+/// use yew_styles::navbar::{navbar_container::NavbarContainer, navbar_item::NavbarItem};
+/// use yew_styles::styles::{Palette, Style};
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+///         App {}
+///     }
+///
+///     fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <AppShell
+///                 navbar_palette=Palette::Primary
+///                 navbar_style=Style::Regular
+///                 branch=html! {<img src="/assets/spielrs_logo.png"></img>}
+///                 header=html! {
+///                     <NavbarContainer>
+///                         <NavbarItem><span>{"Profile"}</span></NavbarItem>
+///                     </NavbarContainer>
+///                 }
+///                 sidebar=html! {<ul><li>{"Dashboard"}</li></ul>}
+///                 footer=html! {<span>{"Spielrs"}</span>}
+///             >
+///                 <h1>{"Dashboard"}</h1>
+///             </AppShell>
+///         }
+///     }
+/// }
+/// ```
+pub struct AppShell {
+    link: ComponentLink<Self>,
+    props: Props,
+    collapsed: bool,
+    drawer_open: bool,
+}
+
+pub enum Msg {
+    ToggleCollapsed,
+    ToggleDrawer,
+    CloseDrawer,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Content of the navbar in the header, usually `NavbarContainer` components. Default `html! {}`
+    #[prop_or_default]
+    pub header: Html,
+    /// Vnode embedded in the beginning of the navbar, useful to include a branch logo
+    #[prop_or_default]
+    pub branch: Html,
+    /// Type navbar style. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub navbar_palette: Palette,
+    /// Navbar styles. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub navbar_style: Style,
+    /// The location of the navbar which is fixed. Default `Fixed::None`
+    #[prop_or(Fixed::None)]
+    pub fixed: Fixed,
+    /// Content of the sidebar, without it there is no sidebar. Default `html! {}`
+    #[prop_or_default]
+    pub sidebar: Html,
+    /// Width of the sidebar. Default `250px`
+    #[prop_or("250px".to_string())]
+    pub sidebar_width: String,
+    /// Content of the footer, without it there is no footer. Default `html! {}`
+    #[prop_or_default]
+    pub footer: Html,
+    /// Signal when the sidebar is toggled, `true` when it is collapsed in big screens
+    /// or opened in small screens
+    #[prop_or(Callback::noop())]
+    pub ontoggle_sidebar_signal: Callback<bool>,
+    /// Min width in pixels of each screen, the sidebar is a drawer in screens smaller than `l`.
+    /// Default `Breakpoints::default()`
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Main content of the application
+    pub children: Children,
+}
+
+impl Component for AppShell {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(APP_SHELL_STYLES);
+
+        AppShell {
+            link,
+            props,
+            collapsed: false,
+            drawer_open: false,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ToggleCollapsed => {
+                self.collapsed = !self.collapsed;
+                self.props.ontoggle_sidebar_signal.emit(self.collapsed);
+            }
+            Msg::ToggleDrawer => {
+                self.drawer_open = !self.drawer_open;
+                self.props.ontoggle_sidebar_signal.emit(self.drawer_open);
+            }
+            Msg::CloseDrawer => {
+                if !self.drawer_open {
+                    return false;
+                }
+
+                self.drawer_open = false;
+                self.props.ontoggle_sidebar_signal.emit(self.drawer_open);
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let with_sidebar = self.props.sidebar != html! {};
+
+        html! {
+            <div
+                class=classes!(
+                    "app-shell",
+                    if self.collapsed { "collapsed" } else { "" },
+                    if self.drawer_open { "drawer-open" } else { "" },
+                    StyleSource::from(get_app_shell_css(
+                        self.props.sidebar_width.clone(),
+                        self.props.navbar_palette.clone(),
+                        self.props.navbar_style.clone(),
+                        &self.props.breakpoints,
+                    )),
+                    self.props.class_name.clone(),
+                    self.props.styles.clone()
+                )
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                <header class="app-shell-header">
+                    {if with_sidebar {
+                        html! {
+                            <>
+                                <button
+                                    class="app-shell-toggle desktop"
+                                    aria-label="Toggle sidebar"
+                                    aria-expanded=(!self.collapsed).to_string()
+                                    onclick=self.link.callback(|_| Msg::ToggleCollapsed)
+                                >
+                                    <UxAssets
+                                        icon=UxIcon::Menu
+                                        size=(String::from("30"), String::from("30"))
+                                    />
+                                </button>
+                                <button
+                                    class="app-shell-toggle mobile"
+                                    aria-label="Toggle sidebar"
+                                    aria-expanded=self.drawer_open.to_string()
+                                    onclick=self.link.callback(|_| Msg::ToggleDrawer)
+                                >
+                                    <UxAssets
+                                        icon=UxIcon::Menu
+                                        size=(String::from("30"), String::from("30"))
+                                    />
+                                </button>
+                            </>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                    <div class="app-shell-navbar">
+                        <Navbar
+                            fixed=self.props.fixed.clone()
+                            navbar_palette=self.props.navbar_palette.clone()
+                            navbar_style=self.props.navbar_style.clone()
+                            branch=self.props.branch.clone()
+                            breakpoints=self.props.breakpoints.clone()
+                        >
+                            {self.props.header.clone()}
+                        </Navbar>
+                    </div>
+                </header>
+                {if with_sidebar {
+                    html! {
+                        <>
+                            <aside class="app-shell-sidebar">
+                                {self.props.sidebar.clone()}
+                            </aside>
+                            <div
+                                class="app-shell-overlay"
+                                onclick=self.link.callback(|_| Msg::CloseDrawer)
+                            />
+                        </>
+                    }
+                } else {
+                    html! {}
+                }}
+                <main class="app-shell-content">
+                    {self.props.children.clone()}
+                </main>
+                {if self.props.footer != html! {} {
+                    html! {
+                        <footer class="app-shell-footer">
+                            {self.props.footer.clone()}
+                        </footer>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+fn get_app_shell_css(
+    sidebar_width: String,
+    navbar_palette: Palette,
+    navbar_style: Style,
+    breakpoints: &Breakpoints,
+) -> String {
+    let breakpoint = breakpoints.l;
+
+    format!(
+        ".app-shell-header {{
+            background: {header_background};
+        }}
+        .app-shell-toggle {{
+            fill: {header_color};
+        }}
+        .app-shell-sidebar {{
+            width: {sidebar_width};
+            background: {sidebar_background};
+        }}
+        @media (min-width: {breakpoint}px) {{
+            .app-shell-toggle.mobile {{
+                display: none;
+            }}
+            &.collapsed .app-shell-sidebar {{
+                width: 0;
+            }}
+        }}
+        @media (max-width: {mobile_breakpoint}px) {{
+            .app-shell-toggle.desktop {{
+                display: none;
+            }}
+            .app-shell-sidebar {{
+                position: fixed;
+                top: 0;
+                bottom: 0;
+                inset-inline-start: 0;
+                z-index: 3;
+                transform: translateX(-100%);
+            }}
+            .app-shell-sidebar:dir(rtl) {{
+                transform: translateX(100%);
+            }}
+            &.drawer-open .app-shell-sidebar {{
+                transform: none;
+            }}
+            &.drawer-open .app-shell-overlay {{
+                display: block;
+                background: {overlay};
+            }}
+        }}",
+        header_background =
+            get_theme_color(navbar_palette.clone(), navbar_style.clone(), ThemeColor::Background),
+        header_color = get_theme_color(navbar_palette, navbar_style, ThemeColor::Color),
+        sidebar_width = sidebar_width,
+        sidebar_background = get_theme_color(Palette::Clean, Style::Regular, ThemeColor::Background),
+        breakpoint = breakpoint,
+        mobile_breakpoint = breakpoint.saturating_sub(1),
+        overlay = get_theme_color(Palette::Standard, Style::Regular, ThemeColor::Overlay),
+    )
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_app_shell() {
+    let props_app_shell = Props {
+        header: html! {},
+        branch: html! {},
+        navbar_palette: Palette::Standard,
+        navbar_style: Style::Regular,
+        fixed: Fixed::None,
+        sidebar: html! {<div id="sidebar">{"Sidebar"}</div>},
+        sidebar_width: "250px".to_string(),
+        footer: html! {<div id="footer">{"Footer"}</div>},
+        ontoggle_sidebar_signal: Callback::noop(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "app-shell-test".to_string(),
+        id: "app-shell-id-test".to_string(),
+        styles: css!("background-color: #918d94;"),
+        children: Children::new(vec![html! {
+            <div id="content">{"Content"}</div>
+        }]),
+    };
+
+    let app_shell: App<AppShell> = App::new();
+
+    app_shell.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props_app_shell,
+    );
+
+    let sidebar_element = utils::document().get_element_by_id("sidebar").unwrap();
+    let content_element = utils::document().get_element_by_id("content").unwrap();
+    let footer_element = utils::document().get_element_by_id("footer").unwrap();
+
+    assert_eq!(sidebar_element.text_content().unwrap(), "Sidebar".to_string());
+    assert_eq!(content_element.text_content().unwrap(), "Content".to_string());
+    assert_eq!(footer_element.text_content().unwrap(), "Footer".to_string());
+}
+
+#[wasm_bindgen_test]
+fn should_collapse_the_sidebar_with_the_desktop_toggle() {
+    let props_app_shell = Props {
+        header: html! {},
+        branch: html! {},
+        navbar_palette: Palette::Standard,
+        navbar_style: Style::Regular,
+        fixed: Fixed::None,
+        sidebar: html! {<div>{"Sidebar"}</div>},
+        sidebar_width: "250px".to_string(),
+        footer: html! {},
+        ontoggle_sidebar_signal: Callback::noop(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "app-shell-toggle-test".to_string(),
+        id: "app-shell-toggle-id-test".to_string(),
+        styles: css!(""),
+        children: Children::new(vec![html! {<div>{"Content"}</div>}]),
+    };
+
+    let app_shell: App<AppShell> = App::new();
+
+    app_shell.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props_app_shell,
+    );
+
+    let app_shell_element = utils::document()
+        .get_element_by_id("app-shell-toggle-id-test")
+        .unwrap();
+    let toggles = app_shell_element.get_elements_by_class_name("app-shell-toggle");
+    let desktop_toggle = toggles.get_with_index(0).unwrap();
+    let mobile_toggle = toggles.get_with_index(1).unwrap();
+
+    assert_eq!(
+        desktop_toggle.get_attribute("aria-expanded"),
+        Some("true".to_string())
+    );
+
+    desktop_toggle.dyn_ref::<HtmlElement>().unwrap().click();
+
+    assert!(app_shell_element.class_list().contains("collapsed"));
+    assert!(!app_shell_element.class_list().contains("drawer-open"));
+    assert_eq!(
+        desktop_toggle.get_attribute("aria-expanded"),
+        Some("false".to_string())
+    );
+    assert_eq!(
+        mobile_toggle.get_attribute("aria-expanded"),
+        Some("false".to_string())
+    );
+}
@@ -1,9 +1,17 @@
-use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::embedded_styles::{mount_styles, BUTTON_STYLES};
+use crate::spinner::{get_inline_spinner, SpinnerType};
+use crate::styles::{get_palette, get_size, get_style, CustomPalette, Palette, Size, Style};
+use crate::theme::{get_font_size_css, get_palette_css, get_theme_color, ThemeColor};
 use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
-use web_sys::window;
+use web_sys::{window, HtmlElement};
 use yew::prelude::*;
 use yew::{utils, App};
+use yew_assets::controller_assets::{ControllerAssets, ControllerIcon};
+use yew_assets::editing_assets::{EditingAssets, EditingIcon};
+use yew_assets::object_assets::{ObjectAssets, ObjectIcon};
+use yew_assets::ux_assets::{UxAssets, UxIcon};
 
 /// # Button component
 ///
@@ -70,6 +78,40 @@ use yew::{utils, App};
 pub struct Button {
     link: ComponentLink<Self>,
     props: ButtonProps,
+    pressed: bool,
+}
+
+/// Type of the button inside of a form
+#[derive(Clone, PartialEq)]
+pub enum ButtonType {
+    Button,
+    Submit,
+    Reset,
+}
+
+/// Icon of yew_assets showed inside of the button
+#[derive(Clone, PartialEq)]
+pub enum ButtonIcon {
+    Ux(UxIcon),
+    Editing(EditingIcon),
+    Controller(ControllerIcon),
+    Object(ObjectIcon),
+}
+
+/// Button with only an icon. The label is not visible but it is read by the screen readers
+#[derive(Clone, PartialEq)]
+pub struct IconOnly {
+    pub icon: ButtonIcon,
+    pub label: String,
+}
+
+impl IconOnly {
+    pub fn new(icon: ButtonIcon, label: &str) -> Self {
+        IconOnly {
+            icon,
+            label: label.to_string(),
+        }
+    }
 }
 
 #[derive(PartialEq)]
@@ -77,11 +119,28 @@ struct ButtonProps {
     button_palette: String,
     button_size: String,
     button_style: String,
+    loading: bool,
+    loading_spinner: SpinnerType,
+    icon_left: Option<ButtonIcon>,
+    icon_right: Option<ButtonIcon>,
+    icon_only: Option<IconOnly>,
+    icon_size: (String, String),
+    toggle: bool,
+    pressed: bool,
+    aria_pressed: Option<bool>,
+    aria_haspopup: Option<String>,
+    aria_expanded: Option<bool>,
+    href: String,
+    target: String,
+    disabled: bool,
+    button_type: String,
+    theme_styles: StyleSource<'static>,
     class_name: String,
     id: String,
     key: String,
     code_ref: NodeRef,
     onclick_signal: Callback<MouseEvent>,
+    ontoggle_signal: Callback<bool>,
     styles: StyleSource<'static>,
     children: Children,
 }
@@ -89,14 +148,44 @@ struct ButtonProps {
 impl From<Props> for ButtonProps {
     fn from(props: Props) -> Self {
         ButtonProps {
+            theme_styles: StyleSource::from(format!(
+                "{}{}&.toggle[aria-pressed=\"true\"] {{ background: {}; }}",
+                get_palette_css(
+                    props.button_palette.clone(),
+                    props.button_style.clone(),
+                    true
+                ),
+                get_font_size_css(props.button_size.clone()),
+                get_theme_color(
+                    props.button_palette.clone(),
+                    props.button_style.clone(),
+                    ThemeColor::BackgroundActive
+                )
+            )),
             button_palette: get_palette(props.button_palette),
+            icon_size: get_icon_size(props.button_size.clone()),
             button_size: get_size(props.button_size),
             button_style: get_style(props.button_style),
+            loading: props.loading,
+            loading_spinner: props.loading_spinner,
+            icon_left: props.icon_left,
+            icon_right: props.icon_right,
+            icon_only: props.icon_only,
+            toggle: props.toggle,
+            pressed: props.pressed,
+            aria_pressed: props.aria_pressed,
+            aria_haspopup: props.aria_haspopup,
+            aria_expanded: props.aria_expanded,
+            href: props.href,
+            target: props.target,
+            disabled: props.disabled,
+            button_type: get_button_type(props.button_type),
             class_name: props.class_name,
             id: props.id,
             key: props.key,
             code_ref: props.code_ref,
             onclick_signal: props.onclick_signal,
+            ontoggle_signal: props.ontoggle_signal,
             styles: props.styles,
             children: props.children,
         }
@@ -120,14 +209,61 @@ pub struct Props {
     /// General property to add keys
     #[prop_or_default]
     pub key: String,
-    /// Three diffent button standard sizes. Default `Size::Medium`
+    /// Five diffent button standard sizes or a custom scale. Default `Size::Medium`
     #[prop_or(Size::Medium)]
     pub button_size: Size,
     /// Button styles. Default `Style::Regular`
     #[prop_or(Style::Regular)]
     pub button_style: Style,
-    /// Click event for button. Required
+    /// Show a spinner instead of the content and ignore the clicks, keeping the width of the button. Default `false`
+    #[prop_or(false)]
+    pub loading: bool,
+    /// Spinner type showed when the button is loading. Default `SpinnerType::Circle`
+    #[prop_or(SpinnerType::Circle)]
+    pub loading_spinner: SpinnerType,
+    /// Icon before the content. Default `None`
+    #[prop_or_default]
+    pub icon_left: Option<ButtonIcon>,
+    /// Icon after the content. Default `None`
+    #[prop_or_default]
+    pub icon_right: Option<ButtonIcon>,
+    /// Show only the icon with an accessible label instead of the content. Default `None`
+    #[prop_or_default]
+    pub icon_only: Option<IconOnly>,
+    /// Keep the button pressed or not after each click. Default `false`
+    #[prop_or(false)]
+    pub toggle: bool,
+    /// Whether the toggle button is pressed. Default `false`
+    #[prop_or(false)]
+    pub pressed: bool,
+    /// Pressed state read by the screen readers when a parent controls it,
+    /// like the active buttons of a `ButtonGroup`. Toggle buttons use `pressed`. Default `None`
+    #[prop_or_default]
+    pub aria_pressed: Option<bool>,
+    /// Kind of popup opened by the button, like `menu`. Default `None`
+    #[prop_or_default]
+    pub aria_haspopup: Option<String>,
+    /// Whether the popup opened by the button is shown. Default `None`
+    #[prop_or_default]
+    pub aria_expanded: Option<bool>,
+    /// Link where the button navigates, with it the button is rendered as an anchor. Default `""`
+    #[prop_or_default]
+    pub href: String,
+    /// Where the link of the button is opened, like `_blank`. Default `""`
+    #[prop_or_default]
+    pub target: String,
+    /// Whether the button is disabled. Default `false`
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Type of the button inside of a form. Default `ButtonType::Button`
+    #[prop_or(ButtonType::Button)]
+    pub button_type: ButtonType,
+    /// Click event for button. Default `noop()`
+    #[prop_or(Callback::noop())]
     pub onclick_signal: Callback<MouseEvent>,
+    /// Signal when the toggle button is pressed or released with the new state
+    #[prop_or(Callback::noop())]
+    pub ontoggle_signal: Callback<bool>,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
@@ -143,8 +279,11 @@ impl Component for Button {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(BUTTON_STYLES);
+
         Self {
             link,
+            pressed: props.pressed,
             props: ButtonProps::from(props),
         }
     }
@@ -152,6 +291,15 @@ impl Component for Button {
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Clicked(mouse_event) => {
+                if self.props.loading || self.props.disabled {
+                    return false;
+                }
+
+                if self.props.toggle {
+                    self.pressed = !self.pressed;
+                    self.props.ontoggle_signal.emit(self.pressed);
+                }
+
                 self.props.onclick_signal.emit(mouse_event);
             }
         };
@@ -162,6 +310,10 @@ impl Component for Button {
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         let prop_mapped = ButtonProps::from(props);
         if self.props != prop_mapped {
+            if self.props.pressed != prop_mapped.pressed {
+                self.pressed = prop_mapped.pressed;
+            }
+
             self.props = prop_mapped;
             return true;
         }
@@ -169,27 +321,189 @@ impl Component for Button {
     }
 
     fn view(&self) -> Html {
-        html! {
-            <button
-                onclick=self.link.callback(Msg::Clicked)
-                class=classes!("button",
-                    self.props.button_palette.clone(),
-                    self.props.button_size.clone(),
-                    self.props.button_style.clone(),
-                    self.props.class_name.clone(),
-                    self.props.styles.clone(),
-                )
-                key=self.props.key.clone()
-                ref=self.props.code_ref.clone()
-                id=self.props.id.clone()
-            > { self.props.children.clone() }
-            </button>
+        let disabled = self.props.disabled || self.props.loading;
+        let class = classes!(
+            "button",
+            if self.props.loading { "loading" } else { "" },
+            if self.props.toggle { "toggle" } else { "" },
+            if self.props.icon_only.is_some() {
+                "icon-only"
+            } else if self.props.icon_left.is_some() || self.props.icon_right.is_some() {
+                "with-icon"
+            } else {
+                ""
+            },
+            self.props.button_palette.clone(),
+            self.props.button_size.clone(),
+            self.props.button_style.clone(),
+            self.props.theme_styles.clone(),
+            self.props.class_name.clone(),
+            self.props.styles.clone(),
+        );
+
+        if self.props.href.is_empty() {
+            html! {
+                <button
+                    onclick=self.link.callback(Msg::Clicked)
+                    class=class
+                    type=self.props.button_type.clone()
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                    id=self.props.id.clone()
+                    disabled=disabled
+                    aria-busy=self.props.loading.to_string()
+                    aria-pressed=self.get_aria_pressed()
+                    aria-haspopup=self.props.aria_haspopup.clone()
+                    aria-expanded=self.props.aria_expanded.map(|expanded| expanded.to_string())
+                >
+                    <span class="button-content">{ self.get_content() }</span>
+                    {get_inline_spinner(
+                        self.props.loading,
+                        self.props.loading_spinner.clone(),
+                        self.props.button_palette.clone(),
+                        self.props.button_style.clone()
+                    )}
+                </button>
+            }
+        } else {
+            html! {
+                <a
+                    onclick=self.link.callback(move |mouse_event: MouseEvent| {
+                        if disabled {
+                            mouse_event.prevent_default();
+                        }
+
+                        Msg::Clicked(mouse_event)
+                    })
+                    class=classes!(class, if disabled { "disabled" } else { "" })
+                    href=self.props.href.clone()
+                    target=self.props.target.clone()
+                    rel={if self.props.target == "_blank" { "noopener noreferrer" } else { "" }}
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                    id=self.props.id.clone()
+                    aria-disabled=disabled.to_string()
+                    aria-busy=self.props.loading.to_string()
+                    aria-pressed=self.get_aria_pressed()
+                    aria-haspopup=self.props.aria_haspopup.clone()
+                    aria-expanded=self.props.aria_expanded.map(|expanded| expanded.to_string())
+                    tabindex={if disabled { "-1" } else { "0" }}
+                >
+                    <span class="button-content">{ self.get_content() }</span>
+                    {get_inline_spinner(
+                        self.props.loading,
+                        self.props.loading_spinner.clone(),
+                        self.props.button_palette.clone(),
+                        self.props.button_style.clone()
+                    )}
+                </a>
+            }
+        }
+    }
+}
+
+impl Button {
+    fn get_aria_pressed(&self) -> Option<String> {
+        if self.props.toggle {
+            Some(self.pressed.to_string())
+        } else {
+            self.props.aria_pressed.map(|pressed| pressed.to_string())
+        }
+    }
+
+    fn get_content(&self) -> Html {
+        if let Some(icon_only) = self.props.icon_only.clone() {
+            html! {
+                <>
+                    {get_icon(icon_only.icon, self.props.icon_size.clone())}
+                    <span class="button-label">{icon_only.label}</span>
+                </>
+            }
+        } else {
+            html! {
+                <>
+                    {get_optional_icon(self.props.icon_left.clone(), self.props.icon_size.clone())}
+                    {self.props.children.clone()}
+                    {get_optional_icon(self.props.icon_right.clone(), self.props.icon_size.clone())}
+                </>
+            }
         }
     }
 }
 
+fn get_optional_icon(icon: Option<ButtonIcon>, size: (String, String)) -> Html {
+    match icon {
+        Some(icon) => get_icon(icon, size),
+        None => html! {},
+    }
+}
+
+fn get_icon(icon: ButtonIcon, size: (String, String)) -> Html {
+    let asset = match icon {
+        ButtonIcon::Ux(icon) => html! {<UxAssets icon=icon size=size />},
+        ButtonIcon::Editing(icon) => html! {<EditingAssets icon=icon size=size />},
+        ButtonIcon::Controller(icon) => html! {<ControllerAssets icon=icon size=size />},
+        ButtonIcon::Object(icon) => html! {<ObjectAssets icon=icon size=size />},
+    };
+
+    html! {
+        <span class="button-icon" aria-hidden="true">{asset}</span>
+    }
+}
+
+fn get_button_type(button_type: ButtonType) -> String {
+    match button_type {
+        ButtonType::Button => "button".to_string(),
+        ButtonType::Submit => "submit".to_string(),
+        ButtonType::Reset => "reset".to_string(),
+    }
+}
+
+fn get_icon_size(size: Size) -> (String, String) {
+    let icon_size = match size {
+        Size::XSmall => 12.0,
+        Size::Small => 14.0,
+        Size::Medium => 20.0,
+        Size::Big => 28.0,
+        Size::XLarge => 36.0,
+        Size::Custom(scale) => (20.0 * scale).round(),
+    };
+
+    (icon_size.to_string(), icon_size.to_string())
+}
+
 wasm_bindgen_test_configure!(run_in_browser);
 
+pub(crate) fn get_button_props(id: &str) -> Props {
+    Props {
+        class_name: String::from("test-button"),
+        id: id.to_string(),
+        key: "".to_string(),
+        code_ref: NodeRef::default(),
+        button_size: Size::Medium,
+        button_style: Style::Regular,
+        onclick_signal: Callback::noop(),
+        button_palette: Palette::Standard,
+        loading: false,
+        loading_spinner: SpinnerType::Circle,
+        icon_left: None,
+        icon_right: None,
+        icon_only: None,
+        toggle: false,
+        pressed: false,
+        aria_pressed: None,
+        aria_haspopup: None,
+        aria_expanded: None,
+        href: "".to_string(),
+        target: "".to_string(),
+        disabled: false,
+        button_type: ButtonType::Button,
+        ontoggle_signal: Callback::noop(),
+        styles: css!(""),
+        children: Children::new(vec![]),
+    }
+}
+
 #[wasm_bindgen_test]
 fn should_trigger_action_when_button_clicked() {
     let body = window().unwrap().document().unwrap().body().unwrap();
@@ -217,16 +531,10 @@ fn should_trigger_action_when_button_clicked() {
     });
 
     let props = Props {
-        class_name: String::from("test-button"),
-        id: String::from("button-id-test"),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        button_size: Size::Medium,
-        button_style: Style::Regular,
         onclick_signal: onchange_name,
-        button_palette: Palette::Standard,
         styles: css!("background-color: #918d94;"),
         children: Children::new(vec![html! {<div id="submenu">{"another menu"}</div>}]),
+        ..get_button_props("button-id-test")
     };
 
     let mouse_event = MouseEvent::new("click").unwrap();
@@ -248,16 +556,9 @@ fn should_trigger_action_when_button_clicked() {
 #[wasm_bindgen_test]
 fn should_create_button_component() {
     let props = Props {
-        class_name: String::from("test-button"),
-        id: String::from("button-id-test"),
-        key: "".to_string(),
-        code_ref: NodeRef::default(),
-        button_size: Size::Medium,
-        button_style: Style::Regular,
-        onclick_signal: Callback::noop(),
-        button_palette: Palette::Standard,
         styles: css!("background-color: #918d94;"),
         children: Children::new(vec![html! {<div id="result">{"result"}</div>}]),
+        ..get_button_props("button-id-test")
     };
 
     let button: App<Button> = App::new();
@@ -276,3 +577,215 @@ fn should_create_button_component() {
     assert_eq!(button_element.tag_name(), "BUTTON");
     assert_eq!(child.id(), "result");
 }
+
+#[wasm_bindgen_test]
+fn should_create_button_with_custom_palette() {
+    let props = Props {
+        button_palette: Palette::Custom(CustomPalette::new("brand", "#1d3557", "#fff", None)),
+        children: Children::new(vec![html! {<div>{"brand"}</div>}]),
+        ..get_button_props("button-custom-palette-test")
+    };
+
+    let button: App<Button> = App::new();
+    button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button_element = utils::document()
+        .get_element_by_id("button-custom-palette-test")
+        .unwrap();
+
+    assert!(button_element.class_list().contains("custom-brand"));
+}
+
+#[wasm_bindgen_test]
+fn should_disable_button_and_show_spinner_when_loading() {
+    let props = Props {
+        button_palette: Palette::Primary,
+        loading: true,
+        loading_spinner: SpinnerType::Wave,
+        children: Children::new(vec![html! {<span>{"save"}</span>}]),
+        ..get_button_props("button-loading-test")
+    };
+
+    let button: App<Button> = App::new();
+    button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button_element = utils::document()
+        .get_element_by_id("button-loading-test")
+        .unwrap();
+
+    let spinner_element = button_element
+        .get_elements_by_class_name("sk-wave")
+        .get_with_index(0);
+
+    assert!(button_element.class_list().contains("loading"));
+    assert!(button_element.has_attribute("disabled"));
+    assert!(spinner_element.is_some());
+}
+
+#[wasm_bindgen_test]
+fn should_create_icon_only_button_with_label() {
+    let props = Props {
+        icon_only: Some(IconOnly::new(ButtonIcon::Editing(EditingIcon::Edit), "Edit")),
+        children: Children::new(vec![html! {<div id="hidden-content">{"content"}</div>}]),
+        ..get_button_props("button-icon-only-test")
+    };
+
+    let button: App<Button> = App::new();
+    button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button_element = utils::document()
+        .get_element_by_id("button-icon-only-test")
+        .unwrap();
+
+    let label_element = button_element
+        .get_elements_by_class_name("button-label")
+        .get_with_index(0)
+        .unwrap();
+
+    assert!(button_element.class_list().contains("icon-only"));
+    assert_eq!(label_element.text_content().unwrap(), "Edit".to_string());
+    assert!(utils::document().get_element_by_id("hidden-content").is_none());
+}
+
+#[wasm_bindgen_test]
+fn should_get_icon_size_from_button_size() {
+    assert_eq!(
+        get_icon_size(Size::Big),
+        ("28".to_string(), "28".to_string())
+    );
+    assert_eq!(
+        get_icon_size(Size::Custom(1.5)),
+        ("30".to_string(), "30".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_toggle_pressed_state_when_clicked() {
+    let body = utils::document().body().unwrap();
+    let element = utils::document().create_element("div").unwrap();
+    element.set_id("toggle-state");
+    body.append_child(&element).unwrap();
+
+    let props = Props {
+        toggle: true,
+        ontoggle_signal: Callback::from(|pressed: bool| {
+            utils::document()
+                .get_element_by_id("toggle-state")
+                .unwrap()
+                .set_text_content(Some(&pressed.to_string()));
+        }),
+        children: Children::new(vec![html! {{"bold"}}]),
+        ..get_button_props("button-toggle-test")
+    };
+
+    let button: App<Button> = App::new();
+    button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button_element = utils::document()
+        .get_element_by_id("button-toggle-test")
+        .unwrap();
+
+    assert_eq!(
+        button_element.get_attribute("aria-pressed"),
+        Some("false".to_string())
+    );
+
+    button_element.dyn_into::<HtmlElement>().unwrap().click();
+
+    let button_element = utils::document()
+        .get_element_by_id("button-toggle-test")
+        .unwrap();
+    let state_element = utils::document().get_element_by_id("toggle-state").unwrap();
+
+    assert_eq!(
+        button_element.get_attribute("aria-pressed"),
+        Some("true".to_string())
+    );
+    assert_eq!(state_element.text_content().unwrap(), "true".to_string());
+}
+
+#[wasm_bindgen_test]
+fn should_create_anchor_when_button_has_href() {
+    let props = Props {
+        button_palette: Palette::Link,
+        href: "https://github.com/spielrs/yew_styles".to_string(),
+        target: "_blank".to_string(),
+        children: Children::new(vec![html! {{"repository"}}]),
+        ..get_button_props("button-link-test")
+    };
+
+    let button: App<Button> = App::new();
+    button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let link_element = utils::document()
+        .get_element_by_id("button-link-test")
+        .unwrap();
+
+    assert_eq!(link_element.tag_name(), "A");
+    assert_eq!(
+        link_element.get_attribute("rel"),
+        Some("noopener noreferrer".to_string())
+    );
+    assert!(link_element.class_list().contains("button"));
+    assert_eq!(link_element.get_attribute("aria-pressed"), None);
+}
+
+#[wasm_bindgen_test]
+fn should_not_trigger_action_when_button_is_disabled() {
+    let body = utils::document().body().unwrap();
+    let element = utils::document().create_element("div").unwrap();
+    element.set_id("disabled-clicks");
+    element.set_text_content(Some("none"));
+    body.append_child(&element).unwrap();
+
+    let props = Props {
+        onclick_signal: Callback::from(|_| {
+            utils::document()
+                .get_element_by_id("disabled-clicks")
+                .unwrap()
+                .set_text_content(Some("clicked"));
+        }),
+        disabled: true,
+        button_type: ButtonType::Submit,
+        children: Children::new(vec![html! {{"send"}}]),
+        ..get_button_props("button-disabled-test")
+    };
+
+    let button: App<Button> = App::new();
+    button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button_element = utils::document()
+        .get_element_by_id("button-disabled-test")
+        .unwrap();
+
+    assert_eq!(
+        button_element.get_attribute("type"),
+        Some("submit".to_string())
+    );
+
+    button_element.dyn_into::<HtmlElement>().unwrap().click();
+
+    let clicks_element = utils::document()
+        .get_element_by_id("disabled-clicks")
+        .unwrap();
+
+    assert_eq!(clicks_element.text_content().unwrap(), "none".to_string());
+}
@@ -0,0 +1,404 @@
+use crate::button::{get_button_props, Button, Props as ButtonProps};
+use crate::embedded_styles::{mount_styles, BUTTON_GROUP_STYLES};
+use crate::styles::{Palette, Size, Style};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::html::ChildrenRenderer;
+use yew::prelude::*;
+use yew::virtual_dom::VChild;
+use yew::{utils, App};
+
+/// # Button Group component
+///
+/// Buttons visually joined that share the palette, the style and the size.
+/// With a selection it works as a segmented control, the active buttons take the `active_style`
+///
+/// ## Features required
+///
+/// button_group
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::{
+///     button::Button,
+///     button_group::{ButtonGroup, Selection},
+///     styles::{Palette, Size},
+/// };
+///
+/// #[derive(Clone, PartialEq)]
+/// pub enum View {
+///     List,
+///     Grid,
+///     Map,
+/// }
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     view: View,
+/// }
+///
+/// pub enum Msg {
+///     ChangeView(Vec<View>),
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App {
+///             link,
+///             view: View::List,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::ChangeView(views) => {
+///                 self.view = views[0].clone();
+///             }
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <ButtonGroup<View>
+///                 button_palette=Palette::Primary
+///                 button_size=Size::Small
+///                 selection=Selection::Single
+///                 values=vec![View::List, View::Grid, View::Map]
+///                 selected=vec![self.view.clone()]
+///                 onchange_signal=self.link.callback(Msg::ChangeView)
+///             >
+///                 <Button>{"List"}</Button>
+///                 <Button>{"Grid"}</Button>
+///                 <Button>{"Map"}</Button>
+///             </ButtonGroup<View>>
+///         }
+///     }
+/// }
+/// ```
+pub struct ButtonGroup<T: Clone + PartialEq + 'static> {
+    link: ComponentLink<Self>,
+    props: Props<T>,
+    selected: Vec<T>,
+}
+
+/// How the buttons of the group are selected
+#[derive(Clone, PartialEq)]
+pub enum Selection {
+    /// The buttons are only actions
+    None,
+    /// Only one button is active at the same time
+    Single,
+    /// Every button can be active or not
+    Multiple,
+}
+
+pub enum Msg {
+    Clicked(usize),
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props<T: Clone + PartialEq + 'static> {
+    /// Type palette of the buttons. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub button_palette: Palette,
+    /// Style of the buttons. Default `Style::Outline`
+    #[prop_or(Style::Outline)]
+    pub button_style: Style,
+    /// Style of the active buttons. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub active_style: Style,
+    /// Size of the buttons. Default `Size::Medium`
+    #[prop_or(Size::Medium)]
+    pub button_size: Size,
+    /// How the buttons are selected. Default `Selection::None`
+    #[prop_or(Selection::None)]
+    pub selection: Selection,
+    /// Value of each button in the same order of the buttons.
+    /// With a selection there must be one value for each button. Default `vec![]`
+    #[prop_or_default]
+    pub values: Vec<T>,
+    /// Active values. Default `vec![]`
+    #[prop_or_default]
+    pub selected: Vec<T>,
+    /// Signal with the active values when the selection changes
+    #[prop_or(Callback::noop())]
+    pub onchange_signal: Callback<Vec<T>>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: ChildrenWithProps<Button>,
+}
+
+impl<T: Clone + PartialEq + 'static> Component for ButtonGroup<T> {
+    type Message = Msg;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(BUTTON_GROUP_STYLES);
+
+        ButtonGroup {
+            link,
+            selected: props.selected.clone(),
+            props,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Clicked(index) => {
+                let value = match self.props.values.get(index) {
+                    Some(value) => value.clone(),
+                    None => return false,
+                };
+
+                let selected =
+                    get_selected_values(&self.props.selection, self.selected.clone(), value);
+
+                if selected == self.selected {
+                    return false;
+                }
+
+                self.selected = selected;
+                self.props.onchange_signal.emit(self.selected.clone());
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.selected != props.selected {
+                self.selected = props.selected.clone();
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        debug_assert!(
+            self.props.selection == Selection::None
+                || self.props.values.len() == self.props.children.len(),
+            "ButtonGroup with a selection needs one value for each button: {} values and {} buttons",
+            self.props.values.len(),
+            self.props.children.len()
+        );
+
+        html! {
+            <div
+                class=classes!("button-group", self.props.class_name.clone(), self.props.styles.clone())
+                role="group"
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.children.iter().enumerate().map(|(index, mut button)| {
+                    let active = self.is_active(index);
+                    let onclick_signal = button.props.onclick_signal.clone();
+
+                    button.props.button_palette = self.props.button_palette.clone();
+                    button.props.button_size = self.props.button_size.clone();
+                    button.props.button_style = if active {
+                        self.props.active_style.clone()
+                    } else {
+                        self.props.button_style.clone()
+                    };
+
+                    if active {
+                        button.props.class_name = format!("{} active", button.props.class_name);
+                    }
+
+                    if self.props.selection != Selection::None {
+                        button.props.aria_pressed = Some(active);
+                    }
+
+                    button.props.onclick_signal = self.link.callback(move |mouse_event: MouseEvent| {
+                        onclick_signal.emit(mouse_event);
+                        Msg::Clicked(index)
+                    });
+
+                    button
+                }).collect::<Html>()}
+            </div>
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> ButtonGroup<T> {
+    fn is_active(&self, index: usize) -> bool {
+        if self.props.selection == Selection::None {
+            return false;
+        }
+
+        match self.props.values.get(index) {
+            Some(value) => self.selected.contains(value),
+            None => false,
+        }
+    }
+}
+
+fn get_selected_values<T: Clone + PartialEq>(
+    selection: &Selection,
+    mut selected: Vec<T>,
+    value: T,
+) -> Vec<T> {
+    match selection {
+        Selection::None => selected,
+        Selection::Single => vec![value],
+        Selection::Multiple => {
+            if let Some(position) = selected.iter().position(|item| *item == value) {
+                selected.remove(position);
+            } else {
+                selected.push(value);
+            }
+
+            selected
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn get_button(id: &str) -> VChild<Button> {
+    let button_props = ButtonProps {
+        class_name: String::from("button-group-item"),
+        children: Children::new(vec![html! {{id}}]),
+        ..get_button_props(id)
+    };
+
+    VChild::new(button_props, NodeRef::default(), None)
+}
+
+#[wasm_bindgen_test]
+fn should_share_palette_style_and_size_with_the_buttons() {
+    let props = Props::<String> {
+        button_palette: Palette::Primary,
+        button_style: Style::Outline,
+        active_style: Style::Regular,
+        button_size: Size::Small,
+        selection: Selection::None,
+        values: vec![],
+        selected: vec![],
+        onchange_signal: Callback::noop(),
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "button-group-test".to_string(),
+        id: "button-group-id-test".to_string(),
+        styles: css!(""),
+        children: ChildrenRenderer::new(vec![get_button("first"), get_button("second")]),
+    };
+
+    let button_group: App<ButtonGroup<String>> = App::new();
+    button_group.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let button_element = utils::document().get_element_by_id("second").unwrap();
+
+    assert!(button_element.class_list().contains("primary"));
+    assert!(button_element.class_list().contains("outline"));
+    assert!(button_element.class_list().contains("small"));
+    assert_eq!(button_element.get_attribute("aria-pressed"), None);
+}
+
+#[wasm_bindgen_test]
+fn should_select_the_value_of_the_button_clicked() {
+    let body = utils::document().body().unwrap();
+    let element = utils::document().create_element("div").unwrap();
+    element.set_id("selected-views");
+    body.append_child(&element).unwrap();
+
+    let props = Props::<String> {
+        button_palette: Palette::Standard,
+        button_style: Style::Outline,
+        active_style: Style::Regular,
+        button_size: Size::Medium,
+        selection: Selection::Single,
+        values: vec!["list".to_string(), "grid".to_string()],
+        selected: vec!["list".to_string()],
+        onchange_signal: Callback::from(|values: Vec<String>| {
+            utils::document()
+                .get_element_by_id("selected-views")
+                .unwrap()
+                .set_text_content(Some(&values.join(",")));
+        }),
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "button-group-test".to_string(),
+        id: "button-group-selection-test".to_string(),
+        styles: css!(""),
+        children: ChildrenRenderer::new(vec![get_button("list"), get_button("grid")]),
+    };
+
+    let button_group: App<ButtonGroup<String>> = App::new();
+    button_group.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    utils::document()
+        .get_element_by_id("grid")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    let grid_element = utils::document().get_element_by_id("grid").unwrap();
+    let list_element = utils::document().get_element_by_id("list").unwrap();
+    let selected_element = utils::document()
+        .get_element_by_id("selected-views")
+        .unwrap();
+
+    assert!(grid_element.class_list().contains("active"));
+    assert!(!list_element.class_list().contains("active"));
+    assert_eq!(
+        grid_element.get_attribute("aria-pressed"),
+        Some("true".to_string())
+    );
+    assert_eq!(
+        list_element.get_attribute("aria-pressed"),
+        Some("false".to_string())
+    );
+    assert_eq!(selected_element.text_content().unwrap(), "grid".to_string());
+}
+
+#[wasm_bindgen_test]
+fn should_toggle_values_in_multiple_selection() {
+    let selected = get_selected_values(&Selection::Multiple, vec![1, 2], 3);
+    assert_eq!(selected, vec![1, 2, 3]);
+
+    let selected = get_selected_values(&Selection::Multiple, selected, 2);
+    assert_eq!(selected, vec![1, 3]);
+
+    let selected = get_selected_values(&Selection::Single, selected, 2);
+    assert_eq!(selected, vec![2]);
+}
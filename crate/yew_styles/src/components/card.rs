@@ -1,8 +1,11 @@
+use crate::embedded_styles::{mount_styles, CARD_STYLES};
 use crate::layouts::{
     container::{AlignContent, Container, Direction, Mode, Wrap},
     item::{Item, ItemLayout},
 };
 use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::theme::get_palette_css;
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -200,7 +203,7 @@ pub struct Props {
     /// Card styles. Default `Style::Regular`
     #[prop_or(Style::Regular)]
     pub card_style: Style,
-    /// three diffent card standard sizes. Default `Size::Medium`
+    /// Five diffent card standard sizes or a custom scale. Default `Size::Medium`
     #[prop_or(Size::Medium)]
     pub card_size: Size,
     /// if hove, focus, active effects are enable. Default `true`
@@ -240,6 +243,8 @@ impl Component for Card {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(CARD_STYLES);
+
         Self { link, props }
     }
 
@@ -297,7 +302,15 @@ impl Component for Card {
                         ""
                     },
                     get_size(self.props.card_size.clone()),
+                    StyleSource::from(get_custom_size_css(self.props.card_size.clone(), |scale| {
+                        format!("&.custom {{ height: calc(250px * {}); }}", scale)
+                    })),
                     get_style(self.props.card_style.clone()),
+                    StyleSource::from(get_palette_css(
+                        self.props.card_palette.clone(),
+                        self.props.card_style.clone(),
+                        self.props.interaction_effect,
+                    )),
                     self.props.class_name.clone(),
                     self.props.styles.clone(),
                 )
@@ -1,3 +1,4 @@
+use crate::embedded_styles::{mount_styles, CAROUSEL_STYLES};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -233,6 +234,8 @@ impl Component for Carousel {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(CAROUSEL_STYLES);
+
         Self { props, link }
     }
 
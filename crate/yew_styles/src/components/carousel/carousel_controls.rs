@@ -1,4 +1,8 @@
+use crate::embedded_styles::{mount_styles, CAROUSEL_STYLES};
 use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::theme::get_palette_css;
+use crate::utils::get_custom_size_css;
+use stylist::StyleSource;
 use wasm_bindgen_test::*;
 use yew::prelude::*;
 use yew::{utils, App};
@@ -204,7 +208,7 @@ pub struct Props {
     /// Type controls style. Default `Palette::Standard`
     #[prop_or(Palette::Standard)]
     pub controls_palette: Palette,
-    /// Three diffent button standard sizes. Default `Size::Medium`
+    /// Five diffent button standard sizes or a custom scale. Default `Size::Medium`
     #[prop_or(Size::Medium)]
     pub controls_size: Size,
     /// General property to get the ref of the component
@@ -231,6 +235,8 @@ impl Component for CarouselControls {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(CAROUSEL_STYLES);
+
         Self { link, props }
     }
 
@@ -257,6 +263,19 @@ impl Component for CarouselControls {
     }
 
     fn view(&self) -> Html {
+        let theme_class = classes!(StyleSource::from(format!(
+            "{}{}",
+            get_palette_css(
+                self.props.controls_palette.clone(),
+                self.props.controls_style.clone(),
+                true,
+            ),
+            get_custom_size_css(self.props.controls_size.clone(), |scale| {
+                format!("&.custom {{ padding: calc(5px * {}); }}", scale)
+            })
+        )))
+        .to_string();
+
         html! {
             <div class="carousel-control"
                 key=self.props.key.clone()
@@ -269,10 +288,11 @@ impl Component for CarouselControls {
                     onclick=self.link.callback(Msg::PrevClicked)>
                     <ControllerAssets
                         size=("50".to_string(),"50".to_string())
-                        class_name=format!("carousel-control-left {} {} {} {}",
+                        class_name=format!("carousel-control-left {} {} {} {} {}",
                             get_size(self.props.controls_size.clone()),
                             get_style(self.props.controls_style.clone()),
                             get_palette(self.props.controls_palette.clone()),
+                            theme_class,
                             self.props.class_name.clone(),
                         )
                         icon=ControllerIcon::ChevronLeft
@@ -284,10 +304,11 @@ impl Component for CarouselControls {
                 >
                     <ControllerAssets
                         size=("50".to_string(),"50".to_string())
-                        class_name=format!("carousel-control-right {} {} {} {}",
+                        class_name=format!("carousel-control-right {} {} {} {} {}",
                         get_size(self.props.controls_size.clone()),
                         get_style(self.props.controls_style.clone()),
                         get_palette(self.props.controls_palette.clone()),
+                        theme_class,
                         self.props.class_name.clone(),
                     )
                         icon=ControllerIcon::ChevronRight/>
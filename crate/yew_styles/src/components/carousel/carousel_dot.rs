@@ -1,4 +1,6 @@
-use crate::styles::{get_palette, Palette};
+use crate::embedded_styles::{mount_styles, CAROUSEL_STYLES};
+use crate::styles::{get_palette, Palette, Style};
+use crate::theme::{get_theme_color, ThemeColor};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -234,6 +236,8 @@ impl Component for CarouselDot {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(CAROUSEL_STYLES);
+
         Self { link, props }
     }
 
@@ -268,6 +272,7 @@ impl Component for CarouselDot {
                     } else {
                         ""
                     },
+                    StyleSource::from(get_carousel_dot_css(self.props.carousel_dot_palette.clone())),
                     self.props.class_name.clone(),
                     self.props.styles.clone(),
                 )
@@ -292,6 +297,27 @@ impl Component for CarouselDot {
     }
 }
 
+fn get_carousel_dot_css(palette: Palette) -> String {
+    format!(
+        "svg {{
+            fill: {};
+        }}
+        &:focus svg {{
+            fill: {};
+        }}
+        &:hover svg {{
+            fill: {};
+        }}
+        &:active svg, &.active svg {{
+            fill: {};
+        }}",
+        get_theme_color(palette.clone(), Style::Light, ThemeColor::Background),
+        get_theme_color(palette.clone(), Style::Light, ThemeColor::BackgroundFocus),
+        get_theme_color(palette.clone(), Style::Light, ThemeColor::BackgroundHover),
+        get_theme_color(palette, Style::Light, ThemeColor::BackgroundActive),
+    )
+}
+
 #[wasm_bindgen_test]
 fn should_create_carousel_dot_component() {
     let props = Props {
@@ -1,3 +1,4 @@
+use crate::embedded_styles::{mount_styles, CAROUSEL_STYLES};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -221,6 +222,8 @@ impl Component for CarouselImage {
     type Properties = Props;
 
     fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        mount_styles(CAROUSEL_STYLES);
+
         Self { props }
     }
 
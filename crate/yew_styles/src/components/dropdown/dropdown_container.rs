@@ -1,4 +1,7 @@
+use crate::embedded_styles::{mount_styles, DROPDOWN_STYLES};
 use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::theme::{get_theme_color, ThemeColor};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -112,6 +115,8 @@ impl Component for Dropdown {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(DROPDOWN_STYLES);
+
         Self {
             props,
             link,
@@ -138,7 +143,24 @@ impl Component for Dropdown {
     fn view(&self) -> Html {
         html! {
             <div
-                class=classes!("dropdown", self.props.class_name.clone(), get_style(self.props.dropdown_style.clone()), get_palette(self.props.dropdown_palette.clone()), get_size(self.props.dropdown_size.clone()), self.props.styles.clone())
+                class=classes!(
+                    "dropdown",
+                    self.props.class_name.clone(),
+                    get_style(self.props.dropdown_style.clone()),
+                    get_palette(self.props.dropdown_palette.clone()),
+                    get_size(self.props.dropdown_size.clone()),
+                    StyleSource::from(get_custom_size_css(self.props.dropdown_size.clone(), |scale| {
+                        format!(
+                            "&.custom .main-content, &.custom li {{ padding: calc(8px * {0}) calc(10px * {0}); }}",
+                            scale
+                        )
+                    })),
+                    StyleSource::from(get_dropdown_css(
+                        self.props.dropdown_palette.clone(),
+                        self.props.dropdown_style.clone()
+                    )),
+                    self.props.styles.clone()
+                )
                 id=self.props.id.clone()
                 key=self.props.key.clone()
                 onclick=self.link.callback(|_| Msg::ShowDropdown)
@@ -162,6 +184,29 @@ fn get_items(active: bool, children: Children) -> Html {
     }
 }
 
+pub(crate) fn get_dropdown_css(palette: Palette, style: Style) -> String {
+    format!(
+        "background: {};
+        color: {};
+        border: {};
+        .dropdown-item:focus {{
+            background: {};
+        }}
+        .dropdown-item:hover {{
+            background: {};
+        }}
+        .dropdown-item:active, .dropdown-item.active {{
+            background: {};
+        }}",
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::Background),
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::Color),
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::Border),
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::BackgroundFocus),
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::BackgroundHover),
+        get_theme_color(palette, style, ThemeColor::BackgroundActive),
+    )
+}
+
 wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
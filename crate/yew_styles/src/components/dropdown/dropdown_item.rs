@@ -1,3 +1,4 @@
+use crate::embedded_styles::{mount_styles, DROPDOWN_STYLES};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -78,6 +79,9 @@ pub struct Props {
     #[prop_or(Callback::noop())]
     /// Click event for dropdown item
     pub onclick_signal: Callback<MouseEvent>,
+    /// Accessible role of the item. Default `None`
+    #[prop_or_default]
+    pub role: Option<String>,
     /// General property to add keys
     #[prop_or_default]
     pub key: String,
@@ -102,6 +106,8 @@ impl Component for DropdownItem {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(DROPDOWN_STYLES);
+
         Self { link, props }
     }
 
@@ -129,6 +135,7 @@ impl Component for DropdownItem {
                 class=classes!("dropdown-item", self.props.class_name.clone(), self.props.styles.clone())
                 id=self.props.id.clone()
                 key=self.props.key.clone()
+                role=self.props.role.clone()
                 onclick=self.link.callback(Msg::Clicked)
             >{self.props.children.clone()}</li>
         }
@@ -139,6 +146,7 @@ impl Component for DropdownItem {
 fn should_create_dropdown_item() {
     let dropdown_item_props = Props {
         onclick_signal: Callback::noop(),
+        role: Some(String::from("menuitem")),
         key: String::from("dropdown-item-1"),
         class_name: String::from("class-test"),
         id: String::from("id-test"),
@@ -156,5 +164,11 @@ fn should_create_dropdown_item() {
     );
 
     let content_element = utils::document().get_element_by_id("item").unwrap();
+    let item_element = utils::document().get_element_by_id("id-test").unwrap();
+
     assert_eq!(content_element.text_content().unwrap(), "Item".to_string());
+    assert_eq!(
+        item_element.get_attribute("role"),
+        Some("menuitem".to_string())
+    );
 }
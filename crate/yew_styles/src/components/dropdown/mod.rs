@@ -1,5 +1,6 @@
 mod dropdown_container;
 mod dropdown_item;
 
+pub(crate) use dropdown_container::get_dropdown_css;
 pub use dropdown_container::Dropdown;
 pub use dropdown_item::DropdownItem;
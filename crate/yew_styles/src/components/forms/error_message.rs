@@ -1,3 +1,4 @@
+use super::form_model::FormModel;
 use yew::prelude::*;
 
 pub fn get_error_message(error_state: bool, error_message: String) -> Html {
@@ -7,3 +8,26 @@ pub fn get_error_message(error_state: bool, error_message: String) -> Html {
         html! {}
     }
 }
+
+pub fn has_field_error(form_model: &Option<FormModel>, name: &str, error_state: bool) -> bool {
+    error_state
+        || form_model
+            .as_ref()
+            .and_then(|form_model| form_model.get_error(name))
+            .is_some()
+}
+
+pub fn get_field_error_message(
+    form_model: &Option<FormModel>,
+    name: &str,
+    error_state: bool,
+    error_message: String,
+) -> Html {
+    match form_model
+        .as_ref()
+        .and_then(|form_model| form_model.get_error(name))
+    {
+        Some(model_error) => get_error_message(true, model_error),
+        None => get_error_message(error_state, error_message),
+    }
+}
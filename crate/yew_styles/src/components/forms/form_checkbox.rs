@@ -0,0 +1,324 @@
+use super::error_message::{get_field_error_message, has_field_error};
+use super::form_model::FormModel;
+use super::form_styles::get_choice_css;
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
+use crate::styles::{get_palette, get_size, Palette, Size};
+use crate::utils::get_custom_size_css;
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Form Checkbox
+///
+/// Checkbox with its label. The indeterminate state is shown until the user changes the checkbox
+/// or the `indeterminate` property changes
+///
+/// ## Features required
+///
+/// forms
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::forms::form_checkbox::FormCheckbox;
+/// use yew_styles::styles::{Palette, Size};
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     newsletter: bool,
+/// }
+///
+/// pub enum Msg {
+///     Newsletter(bool),
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App {
+///             link,
+///             newsletter: false,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Newsletter(newsletter) => {
+///                 self.newsletter = newsletter;
+///             }
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <FormCheckbox
+///                 name="newsletter"
+///                 label="Subscribe to the newsletter"
+///                 checkbox_palette=Palette::Primary
+///                 checkbox_size=Size::Big
+///                 checked=self.newsletter
+///                 onchange_signal=self.link.callback(|(_, checked)| Msg::Newsletter(checked))
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct FormCheckbox {
+    link: ComponentLink<Self>,
+    props: Props,
+    checked: bool,
+    indeterminate: bool,
+    input_ref: NodeRef,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Text of the checkbox. Default `""`
+    #[prop_or_default]
+    pub label: String,
+    /// The name of the checkbox
+    #[prop_or_default]
+    pub name: String,
+    /// Value submitted when the checkbox is checked. Default `"on"`
+    #[prop_or("on".to_string())]
+    pub value: String,
+    /// Whether the checkbox is checked. Default `false`
+    #[prop_or(false)]
+    pub checked: bool,
+    /// Neither checked nor unchecked, like a parent of a partially checked list. Default `false`
+    #[prop_or(false)]
+    pub indeterminate: bool,
+    /// Type palette of the checkbox. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub checkbox_palette: Palette,
+    /// Size of the checkbox. Default `Size::Medium`
+    #[prop_or(Size::Medium)]
+    pub checkbox_size: Size,
+    /// Whether the checkbox is disabled. Default `false`
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// The checkbox must be checked for the form to be submittable. Default `false`
+    #[prop_or(false)]
+    pub required: bool,
+    /// Signal with the value and the checked state when the user changes it
+    #[prop_or(Callback::noop())]
+    pub onchange_signal: Callback<(String, bool)>,
+    /// Error state for validation. Default `false`
+    #[prop_or(false)]
+    pub error_state: bool,
+    /// Show error message when error_state is true
+    #[prop_or_default]
+    pub error_message: String,
+    /// Form model that shows the errors of the field by `name`. Default `None`
+    #[prop_or_default]
+    pub form_model: Option<FormModel>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+}
+
+pub enum Msg {
+    Changed,
+}
+
+impl Component for FormCheckbox {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
+        FormCheckbox {
+            link,
+            checked: props.checked,
+            indeterminate: props.indeterminate,
+            props,
+            input_ref: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Changed => {
+                if self.props.disabled {
+                    return false;
+                }
+
+                self.checked = match self.input_ref.cast::<HtmlInputElement>() {
+                    Some(input) => input.checked(),
+                    None => !self.checked,
+                };
+                self.indeterminate = false;
+                self.props
+                    .onchange_signal
+                    .emit((self.props.value.clone(), self.checked));
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.checked != props.checked {
+                self.checked = props.checked;
+            }
+
+            if self.props.indeterminate != props.indeterminate {
+                self.indeterminate = props.indeterminate;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        if let Some(input) = self.input_ref.cast::<HtmlInputElement>() {
+            input.set_indeterminate(self.indeterminate);
+        }
+    }
+
+    fn view(&self) -> Html {
+        let error = has_field_error(
+            &self.props.form_model,
+            &self.props.name,
+            self.props.error_state,
+        );
+
+        html! {
+            <>
+                <label
+                    class=classes!(
+                        "form-checkbox",
+                        get_palette(self.props.checkbox_palette.clone()),
+                        get_size(self.props.checkbox_size.clone()),
+                        StyleSource::from(get_custom_size_css(self.props.checkbox_size.clone(), |scale| {
+                            format!(
+                                "&.custom input {{ width: calc(16px * {scale}); height: calc(16px * {scale}); }}",
+                                scale = scale
+                            )
+                        })),
+                        StyleSource::from(get_choice_css(self.props.checkbox_palette.clone())),
+                        if self.props.disabled { "disabled" } else { "" },
+                        if error { "error" } else { "" },
+                        self.props.class_name.clone(),
+                        self.props.styles.clone()
+                    )
+                    id=self.props.id.clone()
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                >
+                    <input
+                        type="checkbox"
+                        ref=self.input_ref.clone()
+                        name=self.props.name.clone()
+                        value=self.props.value.clone()
+                        checked=self.checked
+                        disabled=self.props.disabled
+                        required=self.props.required
+                        onchange=self.link.callback(|_| Msg::Changed)
+                        aria-invalid=error.to_string()
+                    />
+                    <span class="form-checkbox-label">{self.props.label.clone()}</span>
+                </label>
+                {get_field_error_message(
+                    &self.props.form_model,
+                    &self.props.name,
+                    self.props.error_state,
+                    self.props.error_message.clone()
+                )}
+            </>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_clear_the_indeterminate_state_when_the_checkbox_changes() {
+    let body = utils::document().body().unwrap();
+    let element = utils::document().create_element("div").unwrap();
+    element.set_id("checkbox-checked");
+    body.append_child(&element).unwrap();
+
+    let props = Props {
+        label: "Select all".to_string(),
+        name: "all".to_string(),
+        value: "all".to_string(),
+        checked: false,
+        indeterminate: true,
+        checkbox_palette: Palette::Primary,
+        checkbox_size: Size::Medium,
+        disabled: false,
+        required: false,
+        onchange_signal: Callback::from(|(value, checked): (String, bool)| {
+            utils::document()
+                .get_element_by_id("checkbox-checked")
+                .unwrap()
+                .set_text_content(Some(&format!("{} {}", value, checked)));
+        }),
+        error_state: false,
+        error_message: "".to_string(),
+        form_model: None,
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "form-checkbox-test".to_string(),
+        id: "form-checkbox-id-test".to_string(),
+        styles: css!(""),
+    };
+
+    let form_checkbox: App<FormCheckbox> = App::new();
+    form_checkbox.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let input_element = utils::document()
+        .get_element_by_id("form-checkbox-id-test")
+        .unwrap()
+        .get_elements_by_tag_name("input")
+        .get_with_index(0)
+        .unwrap()
+        .dyn_into::<HtmlInputElement>()
+        .unwrap();
+
+    assert!(input_element.indeterminate());
+
+    input_element.click();
+
+    let checked_element = utils::document()
+        .get_element_by_id("checkbox-checked")
+        .unwrap();
+
+    assert!(!input_element.indeterminate());
+    assert_eq!(
+        checked_element.text_content().unwrap(),
+        "all true".to_string()
+    );
+}
@@ -1,10 +1,28 @@
+use super::form_model::{FormModel, Validator};
+use super::form_values::{FieldError, FormValues};
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
+use serde::de::DeserializeOwned;
 use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
+use web_sys::{
+    EventTarget, HtmlElement, HtmlFormElement, HtmlInputElement, HtmlSelectElement,
+    HtmlTextAreaElement,
+};
 use yew::prelude::*;
 use yew::{utils, App};
 
 /// # Form
 ///
+/// With a `form_model` the form updates the values and the touched flags of the model fields by `name`,
+/// emits the new model in `onchange_model_signal` and only emits `onsubmit_signal` when the model is valid.
+/// Check `FormModel` to see an example.
+///
+/// On submit the values of the fields are deserialized by `name` in the type `T` of the form
+/// and emitted in `onsubmit_values_signal`, with the errors of the fields if it is not possible.
+/// Check `FormValues` to see how the values are deserialized. Without a type the form is `Form<()>`
+/// and the values can be ignored
+///
 /// ## Features required
 ///
 /// forms
@@ -353,16 +371,27 @@ use yew::{utils, App};
 ///     option.set_selected(true);
 /// }
 /// ```
-pub struct Form {
+pub struct Form<T: Clone + PartialEq + DeserializeOwned + 'static = ()> {
     link: ComponentLink<Self>,
-    props: Props,
+    props: Props<T>,
+    form_model: Option<FormModel>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
-pub struct Props {
+pub struct Props<T: Clone + PartialEq + DeserializeOwned + 'static> {
     /// Signal to emit the event submit. Default
     #[prop_or(Callback::noop())]
     pub onsubmit_signal: Callback<FocusEvent>,
+    /// Signal with the values of the fields deserialized by `name` or the errors of the fields
+    /// when the form is submitted
+    #[prop_or(Callback::noop())]
+    pub onsubmit_values_signal: Callback<Result<T, Vec<FieldError>>>,
+    /// State and validators of the fields. Default `None`
+    #[prop_or_default]
+    pub form_model: Option<FormModel>,
+    /// Signal with the form model updated after an input, a blur or a submit
+    #[prop_or(Callback::noop())]
+    pub onchange_model_signal: Callback<FormModel>,
     pub children: Children,
     /// The URL that processes the form submission
     #[prop_or_default]
@@ -398,28 +427,88 @@ pub enum Method {
 
 pub enum Msg {
     Submitted(FocusEvent),
+    Input(InputData),
+    Blur(FocusEvent),
 }
 
-impl Component for Form {
+impl<T: Clone + PartialEq + DeserializeOwned + 'static> Component for Form<T> {
     type Message = Msg;
-    type Properties = Props;
+    type Properties = Props<T>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        Form { link, props }
+        mount_styles(FORMS_STYLES);
+
+        Form {
+            link,
+            form_model: props.form_model.clone(),
+            props,
+        }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::Submitted(value) => {
                 value.prevent_default();
+
+                if let Some(form_model) = self.form_model.as_mut() {
+                    let valid = form_model.validate();
+                    self.props.onchange_model_signal.emit(form_model.clone());
+
+                    if !valid {
+                        return false;
+                    }
+                }
+
+                let form_values = value
+                    .target()
+                    .and_then(|target| target.dyn_into::<HtmlFormElement>().ok())
+                    .map(|form| FormValues::from_form(&form))
+                    .unwrap_or_default();
+
+                self.props
+                    .onsubmit_values_signal
+                    .emit(form_values.deserialize());
                 self.props.onsubmit_signal.emit(value);
             }
+            Msg::Input(input_data) => {
+                if let (Some(form_model), Some((name, value))) = (
+                    self.form_model.as_mut(),
+                    get_field_value(input_data.event.target()),
+                ) {
+                    if form_model.set_value(&name, &value) {
+                        self.props.onchange_model_signal.emit(form_model.clone());
+                    }
+                }
+
+                return false;
+            }
+            Msg::Blur(focus_event) => {
+                if let (Some(form_model), Some((name, _))) = (
+                    self.form_model.as_mut(),
+                    get_field_value(focus_event.target()),
+                ) {
+                    let untouched = form_model
+                        .get_field(&name)
+                        .map_or(false, |field| !field.touched);
+
+                    if untouched {
+                        form_model.touch(&name);
+                        self.props.onchange_model_signal.emit(form_model.clone());
+                    }
+                }
+
+                return false;
+            }
         };
         true
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            if self.props.form_model != props.form_model {
+                self.form_model = props.form_model.clone();
+            }
+
             self.props = props;
             true
         } else {
@@ -431,6 +520,8 @@ impl Component for Form {
         html! {
             <form
                 onsubmit=self.link.callback(Msg::Submitted)
+                oninput=self.link.callback(Msg::Input)
+                onfocusout=self.link.callback(Msg::Blur)
                 action=self.props.action.clone()
                 method=get_method(self.props.method.clone())
                 name=self.props.name.clone()
@@ -453,14 +544,36 @@ fn get_method(method: Method) -> String {
     }
 }
 
+fn get_field_value(target: Option<EventTarget>) -> Option<(String, String)> {
+    let target = target?;
+
+    if let Some(input) = target.dyn_ref::<HtmlInputElement>() {
+        let value = match input.type_().as_str() {
+            "checkbox" | "radio" if !input.checked() => String::new(),
+            _ => input.value(),
+        };
+
+        Some((input.name(), value))
+    } else if let Some(select) = target.dyn_ref::<HtmlSelectElement>() {
+        Some((select.name(), select.value()))
+    } else {
+        target
+            .dyn_ref::<HtmlTextAreaElement>()
+            .map(|textarea| (textarea.name(), textarea.value()))
+    }
+}
+
 #[wasm_bindgen_test]
 fn should_create_form_component() {
-    let props = Props {
+    let props = Props::<()> {
         key: "".to_string(),
         code_ref: NodeRef::default(),
         class_name: "form-test".to_string(),
         id: "form-test-id".to_string(),
         onsubmit_signal: Callback::noop(),
+        form_model: None,
+        onchange_model_signal: Callback::noop(),
+        onsubmit_values_signal: Callback::noop(),
         method: Method::Post,
         action: "".to_string(),
         name: "form-test".to_string(),
@@ -496,12 +609,15 @@ fn should_submit_the_form() {
         content.set_text_content(Some("form submitted"));
     });
 
-    let props = Props {
+    let props = Props::<()> {
         key: "".to_string(),
         code_ref: NodeRef::default(),
         class_name: "form-test".to_string(),
         id: "form-test-id".to_string(),
         onsubmit_signal: onsubmit,
+        form_model: None,
+        onchange_model_signal: Callback::noop(),
+        onsubmit_values_signal: Callback::noop(),
         method: Method::Post,
         action: "".to_string(),
         name: "form-test".to_string(),
@@ -520,3 +636,148 @@ fn should_submit_the_form() {
         "form submitted".to_string()
     );
 }
+
+#[wasm_bindgen_test]
+fn should_block_the_submit_while_the_form_model_is_invalid() {
+    let body = utils::document().body().unwrap();
+
+    let element = utils::document().create_element("div").unwrap();
+    element.set_text_content(Some("not submitted"));
+    element.set_id("form-model-submit");
+
+    body.append_child(&element).unwrap();
+
+    let props = Props::<()> {
+        key: "".to_string(),
+        code_ref: NodeRef::default(),
+        class_name: "form-test".to_string(),
+        id: "form-model-test-id".to_string(),
+        onsubmit_signal: Callback::from(|_| {
+            utils::document()
+                .get_element_by_id("form-model-submit")
+                .unwrap()
+                .set_text_content(Some("submitted"));
+        }),
+        form_model: Some(FormModel::new().field(
+            "email",
+            "",
+            vec![Validator::Required("required".to_string())],
+        )),
+        onchange_model_signal: Callback::from(|form_model: FormModel| {
+            utils::document()
+                .get_element_by_id("form-model-error")
+                .unwrap()
+                .set_text_content(form_model.get_error("email").as_deref());
+        }),
+        onsubmit_values_signal: Callback::noop(),
+        method: Method::Post,
+        action: "".to_string(),
+        name: "form-test".to_string(),
+        styles: css!(""),
+        children: Children::new(vec![html! {
+            <>
+                <input name="email"/>
+                <span id="form-model-error"></span>
+                <button type="submit" id="form-model-button">{"Submit"}</button>
+            </>
+        }]),
+    };
+
+    let form_component: App<Form> = App::new();
+
+    form_component.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    utils::document()
+        .get_element_by_id("form-model-button")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    let submit_element = utils::document()
+        .get_element_by_id("form-model-submit")
+        .unwrap();
+    let error_element = utils::document()
+        .get_element_by_id("form-model-error")
+        .unwrap();
+
+    assert_eq!(
+        submit_element.text_content().unwrap(),
+        "not submitted".to_string()
+    );
+    assert_eq!(
+        error_element.text_content().unwrap(),
+        "required".to_string()
+    );
+}
+
+#[derive(Clone, PartialEq, serde::Deserialize)]
+struct SignUp {
+    email: String,
+    age: u8,
+}
+
+#[wasm_bindgen_test]
+fn should_submit_the_values_deserialized() {
+    let body = utils::document().body().unwrap();
+
+    let element = utils::document().create_element("div").unwrap();
+    element.set_id("form-values-result");
+
+    body.append_child(&element).unwrap();
+
+    let props = Props::<SignUp> {
+        key: "".to_string(),
+        code_ref: NodeRef::default(),
+        class_name: "form-test".to_string(),
+        id: "form-values-test-id".to_string(),
+        onsubmit_signal: Callback::noop(),
+        form_model: None,
+        onchange_model_signal: Callback::noop(),
+        onsubmit_values_signal: Callback::from(|result: Result<SignUp, Vec<FieldError>>| {
+            let sign_up = result.unwrap();
+
+            utils::document()
+                .get_element_by_id("form-values-result")
+                .unwrap()
+                .set_text_content(Some(&format!("{} {}", sign_up.email, sign_up.age)));
+        }),
+        method: Method::Post,
+        action: "".to_string(),
+        name: "form-test".to_string(),
+        styles: css!(""),
+        children: Children::new(vec![html! {
+            <>
+                <input name="email" value="spieljs@gmail.com"/>
+                <input name="age" type="number" value="30"/>
+                <button type="submit" id="form-values-button">{"Submit"}</button>
+            </>
+        }]),
+    };
+
+    let form_component: App<Form<SignUp>> = App::new();
+
+    form_component.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    utils::document()
+        .get_element_by_id("form-values-button")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    let result_element = utils::document()
+        .get_element_by_id("form-values-result")
+        .unwrap();
+
+    assert_eq!(
+        result_element.text_content().unwrap(),
+        "spieljs@gmail.com 30".to_string()
+    );
+}
@@ -1,5 +1,9 @@
-use super::error_message::get_error_message;
+use super::error_message::{get_field_error_message, has_field_error};
+use super::form_model::FormModel;
+use super::form_styles::get_form_css;
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
 use crate::styles::{get_palette, get_size, Palette, Size};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -40,6 +44,9 @@ pub struct Props {
     /// Show error message when error_state is true
     #[prop_or_default]
     pub error_message: String,
+    /// Form model that shows the errors of the field by `name`. Default `None`
+    #[prop_or_default]
+    pub form_model: Option<FormModel>,
     /// Alt attribute for the image type
     #[prop_or_default]
     pub alt: String,
@@ -85,6 +92,8 @@ impl Component for FormFile {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
         Self { link, props }
     }
 
@@ -108,6 +117,12 @@ impl Component for FormFile {
     }
 
     fn view(&self) -> Html {
+        let error = has_field_error(
+            &self.props.form_model,
+            &self.props.name,
+            self.props.error_state,
+        );
+
         html! {
             <>
                 <input
@@ -117,8 +132,16 @@ impl Component for FormFile {
                         "form-file",
                         get_palette(self.props.input_palette.clone()),
                         get_size(self.props.input_size.clone()),
+                        StyleSource::from(get_custom_size_css(self.props.input_size.clone(), |scale| {
+                            format!("&.custom {{ padding: calc(5px * {}); }}", scale)
+                        })),
                         if self.props.underline { "underline" } else { "" },
                         if self.props.hidden { "hidden" } else { "" },
+                        if error { "error" } else { "" },
+                        StyleSource::from(get_form_css(
+                            self.props.input_palette.clone(),
+                            self.props.underline
+                        )),
                         self.props.class_name.clone(),
                         self.props.styles.clone(),
                     )
@@ -134,8 +157,14 @@ impl Component for FormFile {
                     readonly=self.props.readonly
                     disabled=self.props.disabled
                     autofocus=self.props.autofocus
+                    aria-invalid=error.to_string()
                 />
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_field_error_message(
+                    &self.props.form_model,
+                    &self.props.name,
+                    self.props.error_state,
+                    self.props.error_message.clone()
+                )}
             </>
         }
     }
@@ -152,6 +181,7 @@ fn should_create_form_input() {
         onchange_signal: Callback::noop(),
         error_message: "invalid input".to_string(),
         error_state: false,
+        form_model: None,
         name: "input-test".to_string(),
         input_palette: Palette::Standard,
         input_size: Size::Medium,
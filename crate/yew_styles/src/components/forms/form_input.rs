@@ -1,5 +1,9 @@
-use super::error_message::get_error_message;
+use super::error_message::{get_field_error_message, has_field_error};
+use super::form_model::{FormModel, Validator};
+use super::form_styles::get_form_css;
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
 use crate::styles::{get_palette, get_size, Palette, Size};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -182,6 +186,9 @@ pub struct Props {
     /// Show error message when error_state is true
     #[prop_or_default]
     pub error_message: String,
+    /// Form model that shows the errors of the field by `name`. Default `None`
+    #[prop_or_default]
+    pub form_model: Option<FormModel>,
     /// Set css styles directly in the component
     #[prop_or(css!(""))]
     pub styles: StyleSource<'static>,
@@ -199,6 +206,8 @@ impl Component for FormInput {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
         Self { link, props }
     }
 
@@ -228,6 +237,12 @@ impl Component for FormInput {
     }
 
     fn view(&self) -> Html {
+        let error = has_field_error(
+            &self.props.form_model,
+            &self.props.name,
+            self.props.error_state,
+        );
+
         html! {
             <>
                 <input
@@ -236,7 +251,15 @@ impl Component for FormInput {
                         "form-input",
                         get_palette(self.props.input_palette.clone()),
                         get_size(self.props.input_size.clone()),
+                        StyleSource::from(get_custom_size_css(self.props.input_size.clone(), |scale| {
+                            format!("&.custom {{ padding: calc(5px * {}); }}", scale)
+                        })),
                         if self.props.underline { "underline" } else { "" },
+                        if error { "error" } else { "" },
+                        StyleSource::from(get_form_css(
+                            self.props.input_palette.clone(),
+                            self.props.underline
+                        )),
                         self.props.class_name.clone(),
                         self.props.styles.clone(),
                     )
@@ -262,8 +285,14 @@ impl Component for FormInput {
                     autocomplete=self.props.autocomplete.to_string()
                     step=self.props.step.to_string()
                     list=self.props.list.clone()
+                    aria-invalid=error.to_string()
                 />
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_field_error_message(
+                    &self.props.form_model,
+                    &self.props.name,
+                    self.props.error_state,
+                    self.props.error_message.clone()
+                )}
             </>
         }
     }
@@ -309,6 +338,7 @@ fn should_create_form_input() {
         checked: false,
         error_message: "invalid input".to_string(),
         error_state: false,
+        form_model: None,
         name: "input-test".to_string(),
         input_palette: Palette::Standard,
         input_size: Size::Medium,
@@ -343,3 +373,64 @@ fn should_create_form_input() {
 
     assert_eq!(form_input_element.tag_name(), "INPUT");
 }
+
+#[wasm_bindgen_test]
+fn should_mark_the_input_as_invalid_with_the_error_of_the_model() {
+    let mut form_model = FormModel::new().field(
+        "email",
+        "",
+        vec![Validator::Required("Email is required".to_string())],
+    );
+    form_model.touch("email");
+
+    let props = Props {
+        key: "".to_string(),
+        code_ref: NodeRef::default(),
+        id: "form-input-invalid-test".to_string(),
+        class_name: "".to_string(),
+        input_type: InputType::Email,
+        oninput_signal: Callback::noop(),
+        onblur_signal: Callback::noop(),
+        onkeydown_signal: Callback::noop(),
+        checked: false,
+        error_message: "".to_string(),
+        error_state: false,
+        form_model: Some(form_model),
+        name: "email".to_string(),
+        input_palette: Palette::Standard,
+        input_size: Size::Medium,
+        placeholder: "".to_string(),
+        required: false,
+        autocomplete: false,
+        autofocus: false,
+        alt: "".to_string(),
+        pattern: "".to_string(),
+        min: 0,
+        max: 0,
+        maxlength: 100,
+        minlength: 0,
+        readonly: false,
+        underline: false,
+        disabled: false,
+        step: 1,
+        list: "".to_string(),
+        styles: css!(""),
+    };
+
+    let form_input: App<FormInput> = App::new();
+
+    form_input.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let form_input_element = utils::document()
+        .get_element_by_id("form-input-invalid-test")
+        .unwrap();
+
+    assert!(form_input_element.class_list().contains("error"));
+    assert_eq!(
+        form_input_element.get_attribute("aria-invalid"),
+        Some("true".to_string())
+    );
+}
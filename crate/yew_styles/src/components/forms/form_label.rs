@@ -1,3 +1,4 @@
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -99,6 +100,8 @@ impl Component for FormLabel {
     type Properties = Props;
 
     fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
         Self { props }
     }
 
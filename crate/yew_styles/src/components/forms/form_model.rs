@@ -0,0 +1,451 @@
+use js_sys::RegExp;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(constructor, catch, js_class = "RegExp")]
+    fn try_new_reg_exp(pattern: &str, flags: &str) -> Result<RegExp, JsValue>;
+}
+
+/// # Form Model
+///
+/// State of the form fields: value, touched and dirty flags and the errors of its validators.
+/// Pass it to the `Form` and to the fields with the `form_model` property and the fields will show
+/// the first error of the validators once they are touched. The `Form` updates the model with every
+/// input and blur of the fields, matching them by `name`, and it blocks the submit while the model is invalid
+///
+/// ## Features required
+///
+/// forms
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::forms::{
+///     form_component::Form,
+///     form_input::{FormInput, InputType},
+///     form_model::{FormModel, Validator},
+///     form_submit::FormSubmit,
+/// };
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     form_model: FormModel,
+/// }
+///
+/// pub enum Msg {
+///     ChangeModel(FormModel),
+///     Submit,
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App {
+///             link,
+///             form_model: FormModel::new()
+///                 .field("email", "", vec![
+///                     Validator::Required("Email is required".to_string()),
+///                     Validator::Email("Email is not valid".to_string()),
+///                 ])
+///                 .field("password", "", vec![
+///                     Validator::MinLength(8, "At least 8 characters".to_string()),
+///                     Validator::custom(|value| {
+///                         if value.chars().any(|c| c.is_numeric()) {
+///                             None
+///                         } else {
+///                             Some("At least one number".to_string())
+///                         }
+///                     }),
+///                 ]),
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::ChangeModel(form_model) => {
+///                 self.form_model = form_model;
+///             }
+///             Msg::Submit => {
+///                 // only emitted when every field is valid
+///             }
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <Form
+///                 form_model=Some(self.form_model.clone())
+///                 onchange_model_signal=self.link.callback(Msg::ChangeModel)
+///                 onsubmit_signal=self.link.callback(|_| Msg::Submit)
+///             >
+///                 <FormInput
+///                     name="email"
+///                     input_type=InputType::Email
+///                     form_model=Some(self.form_model.clone())
+///                 />
+///                 <FormInput
+///                     name="password"
+///                     input_type=InputType::Password
+///                     form_model=Some(self.form_model.clone())
+///                 />
+///                 <FormSubmit value="Sign in" />
+///             </Form>
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Default, PartialEq)]
+pub struct FormModel {
+    fields: Vec<FormField>,
+}
+
+/// State of one field of the form model
+#[derive(Clone, PartialEq)]
+pub struct FormField {
+    /// Name of the field, the same as the `name` of the form component
+    pub name: String,
+    /// Current value of the field
+    pub value: String,
+    /// The field lost the focus at least once or the form was submitted
+    pub touched: bool,
+    /// The value is different from the initial value
+    pub dirty: bool,
+    /// Errors of the validators with the current value
+    pub errors: Vec<String>,
+    initial_value: String,
+    validators: Vec<Validator>,
+}
+
+/// Validators of the form model fields. Every validator except `Required` and `Custom`
+/// is valid with an empty value, like the html constraint validation
+#[derive(Clone)]
+pub enum Validator {
+    /// The value can not be empty
+    Required(String),
+    /// Minimum number of characters
+    MinLength(usize, String),
+    /// Maximum number of characters
+    MaxLength(usize, String),
+    /// Javascript regular expression that the whole value must match, created with `Validator::pattern`
+    Pattern(Pattern, String),
+    /// The value must be an email address
+    Email(String),
+    /// Closure that returns the error message when the value is invalid
+    Custom(Rc<dyn Fn(&str) -> Option<String>>),
+}
+
+/// Compiled regular expression of `Validator::Pattern`
+#[derive(Clone)]
+pub struct Pattern {
+    source: String,
+    reg_exp: RegExp,
+}
+
+impl Pattern {
+    /// The regular expression as it was written
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Validator {
+    /// Creates a `Validator::Pattern` compiling the javascript regular expression.
+    /// Returns the error of the regular expression if it is not valid
+    pub fn pattern(pattern: &str, message: &str) -> Result<Self, String> {
+        match try_new_reg_exp(&format!("^(?:{})$", pattern), "u") {
+            Ok(reg_exp) => Ok(Validator::Pattern(
+                Pattern {
+                    source: pattern.to_string(),
+                    reg_exp,
+                },
+                message.to_string(),
+            )),
+            Err(error) => Err(error
+                .dyn_into::<js_sys::Error>()
+                .map(|error| String::from(error.message()))
+                .unwrap_or_else(|_| format!("Invalid regular expression: {}", pattern))),
+        }
+    }
+
+    /// Creates a `Validator::Custom` from a closure
+    pub fn custom<F: Fn(&str) -> Option<String> + 'static>(validator: F) -> Self {
+        Validator::Custom(Rc::new(validator))
+    }
+
+    fn validate(&self, value: &str) -> Option<String> {
+        let valid = match self {
+            Validator::Required(_) => !value.trim().is_empty(),
+            Validator::MinLength(min, _) => value.is_empty() || value.chars().count() >= *min,
+            Validator::MaxLength(max, _) => value.chars().count() <= *max,
+            Validator::Pattern(pattern, _) => value.is_empty() || pattern.reg_exp.test(value),
+            Validator::Email(_) => value.is_empty() || is_email(value),
+            Validator::Custom(validator) => return validator(value),
+        };
+
+        if valid {
+            None
+        } else {
+            Some(self.get_message())
+        }
+    }
+
+    fn get_message(&self) -> String {
+        match self {
+            Validator::Required(message)
+            | Validator::MinLength(_, message)
+            | Validator::MaxLength(_, message)
+            | Validator::Pattern(_, message)
+            | Validator::Email(message) => message.clone(),
+            Validator::Custom(_) => String::new(),
+        }
+    }
+}
+
+impl PartialEq for Validator {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Validator::Custom(validator), Validator::Custom(other_validator)) => {
+                Rc::as_ptr(validator) as *const u8 == Rc::as_ptr(other_validator) as *const u8
+            }
+            (
+                Validator::MinLength(length, message),
+                Validator::MinLength(other_length, other_message),
+            )
+            | (
+                Validator::MaxLength(length, message),
+                Validator::MaxLength(other_length, other_message),
+            ) => length == other_length && message == other_message,
+            (
+                Validator::Pattern(pattern, message),
+                Validator::Pattern(other_pattern, other_message),
+            ) => pattern == other_pattern && message == other_message,
+            (Validator::Required(message), Validator::Required(other_message))
+            | (Validator::Email(message), Validator::Email(other_message)) => {
+                message == other_message
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FormModel {
+    pub fn new() -> Self {
+        FormModel { fields: vec![] }
+    }
+
+    /// Adds a field with its initial value and validators
+    pub fn field(mut self, name: &str, initial_value: &str, validators: Vec<Validator>) -> Self {
+        let errors = get_errors(&validators, initial_value);
+
+        self.fields.retain(|field| field.name != name);
+        self.fields.push(FormField {
+            name: name.to_string(),
+            value: initial_value.to_string(),
+            touched: false,
+            dirty: false,
+            errors,
+            initial_value: initial_value.to_string(),
+            validators,
+        });
+
+        self
+    }
+
+    /// Sets the value of the field and validates it. Returns `false` if the field does not exist
+    pub fn set_value(&mut self, name: &str, value: &str) -> bool {
+        match self.get_field_mut(name) {
+            Some(field) => {
+                field.value = value.to_string();
+                field.dirty = field.value != field.initial_value;
+                field.errors = get_errors(&field.validators, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Marks the field as touched. Returns `false` if the field does not exist
+    pub fn touch(&mut self, name: &str) -> bool {
+        match self.get_field_mut(name) {
+            Some(field) => {
+                field.touched = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Validates every field and marks them as touched to show all the errors
+    pub fn validate(&mut self) -> bool {
+        for field in self.fields.iter_mut() {
+            field.errors = get_errors(&field.validators, &field.value);
+            field.touched = true;
+        }
+
+        self.is_valid()
+    }
+
+    /// Sets the initial values back and removes the touched and dirty flags
+    pub fn reset(&mut self) {
+        for field in self.fields.iter_mut() {
+            field.value = field.initial_value.clone();
+            field.touched = false;
+            field.dirty = false;
+            field.errors = get_errors(&field.validators, &field.value);
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|field| field.errors.is_empty())
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.fields.iter().any(|field| field.dirty)
+    }
+
+    pub fn has_field(&self, name: &str) -> bool {
+        self.get_field(name).is_some()
+    }
+
+    pub fn get_field(&self, name: &str) -> Option<&FormField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    pub fn get_fields(&self) -> &[FormField] {
+        &self.fields
+    }
+
+    /// Value of the field, empty if the field does not exist
+    pub fn get_value(&self, name: &str) -> String {
+        self.get_field(name)
+            .map(|field| field.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// First error of the field when it is touched
+    pub fn get_error(&self, name: &str) -> Option<String> {
+        self.get_field(name)
+            .filter(|field| field.touched)
+            .and_then(|field| field.errors.first().cloned())
+    }
+
+    fn get_field_mut(&mut self, name: &str) -> Option<&mut FormField> {
+        self.fields.iter_mut().find(|field| field.name == name)
+    }
+}
+
+fn get_errors(validators: &[Validator], value: &str) -> Vec<String> {
+    validators
+        .iter()
+        .filter_map(|validator| validator.validate(value))
+        .collect()
+}
+
+fn is_email(value: &str) -> bool {
+    let mut parts = value.split('@');
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(local), Some(domain), None) => {
+            !local.is_empty()
+                && !value.contains(char::is_whitespace)
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        }
+        _ => false,
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_validate_the_fields_of_the_model() {
+    let mut form_model = FormModel::new()
+        .field(
+            "email",
+            "",
+            vec![
+                Validator::Required("required".to_string()),
+                Validator::Email("email".to_string()),
+            ],
+        )
+        .field(
+            "code",
+            "",
+            vec![
+                Validator::pattern("[A-Z]{3}", "pattern").unwrap(),
+                Validator::MaxLength(3, "max".to_string()),
+            ],
+        );
+
+    assert!(!form_model.is_valid());
+    assert_eq!(form_model.get_error("email"), None);
+
+    form_model.set_value("email", "spielrs@");
+    form_model.touch("email");
+
+    assert_eq!(form_model.get_error("email"), Some("email".to_string()));
+
+    form_model.set_value("email", "spieljs@gmail.com");
+    form_model.set_value("code", "abcd");
+
+    assert_eq!(
+        form_model.get_field("code").unwrap().errors,
+        vec!["pattern".to_string(), "max".to_string()]
+    );
+
+    form_model.set_value("code", "ABC");
+
+    assert!(form_model.is_valid());
+    assert!(form_model.is_dirty());
+}
+
+#[wasm_bindgen_test]
+fn should_touch_all_the_fields_when_the_model_is_validated() {
+    let mut form_model = FormModel::new().field(
+        "password",
+        "",
+        vec![Validator::custom(|value| {
+            if value.chars().any(|c| c.is_numeric()) {
+                None
+            } else {
+                Some("number".to_string())
+            }
+        })],
+    );
+
+    assert_eq!(form_model.get_error("password"), None);
+    assert!(!form_model.validate());
+    assert_eq!(form_model.get_error("password"), Some("number".to_string()));
+
+    form_model.reset();
+
+    assert!(!form_model.get_field("password").unwrap().touched);
+}
+
+#[wasm_bindgen_test]
+fn should_return_an_error_with_an_invalid_pattern() {
+    assert!(Validator::pattern("[A-Z", "pattern").is_err());
+    assert!(
+        Validator::pattern("[A-Z]+", "pattern").unwrap()
+            == Validator::pattern("[A-Z]+", "pattern").unwrap()
+    );
+}
@@ -0,0 +1,467 @@
+use super::error_message::{get_field_error_message, has_field_error};
+use super::form_group::Orientation;
+use super::form_model::FormModel;
+use super::form_styles::get_choice_css;
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
+use crate::styles::{get_palette, get_size, Palette, Size};
+use crate::utils::get_custom_size_css;
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{window, HtmlElement};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Form Radio Group
+///
+/// Radios with the same `name` where only one option is selected. The value of each option
+/// can be any type that implements `ToString`, the string is the value submitted with the form.
+/// The arrow keys move the focus and the selection between the enabled options
+///
+/// ## Features required
+///
+/// forms
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::forms::form_radio_group::{FormRadioGroup, RadioOption};
+/// use yew_styles::styles::Palette;
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     plan: Option<String>,
+/// }
+///
+/// pub enum Msg {
+///     ChangePlan(String),
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App {
+///             link,
+///             plan: None,
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::ChangePlan(plan) => {
+///                 self.plan = Some(plan);
+///             }
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <FormRadioGroup<String>
+///                 name="plan"
+///                 radio_palette=Palette::Primary
+///                 options=vec![
+///                     RadioOption::new("free".to_string(), "Free"),
+///                     RadioOption::new("pro".to_string(), "Pro"),
+///                     RadioOption::new("team".to_string(), "Team"),
+///                 ]
+///                 selected=self.plan.clone()
+///                 onchange_signal=self.link.callback(Msg::ChangePlan)
+///             />
+///         }
+///     }
+/// }
+/// ```
+pub struct FormRadioGroup<T: Clone + PartialEq + ToString + 'static> {
+    link: ComponentLink<Self>,
+    props: Props<T>,
+    selected: Option<T>,
+    option_refs: Vec<NodeRef>,
+}
+
+/// Option of the radio group
+#[derive(Clone, PartialEq)]
+pub struct RadioOption<T: Clone + PartialEq + ToString> {
+    /// Value of the option
+    pub value: T,
+    /// Text of the option
+    pub label: String,
+    /// Whether the option is disabled
+    pub disabled: bool,
+}
+
+impl<T: Clone + PartialEq + ToString> RadioOption<T> {
+    pub fn new(value: T, label: &str) -> Self {
+        RadioOption {
+            value,
+            label: label.to_string(),
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(value: T, label: &str) -> Self {
+        RadioOption {
+            value,
+            label: label.to_string(),
+            disabled: true,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props<T: Clone + PartialEq + ToString + 'static> {
+    /// The name of the radios in the group. Required
+    pub name: String,
+    /// Options of the group
+    #[prop_or_default]
+    pub options: Vec<RadioOption<T>>,
+    /// Value of the selected option. Default `None`
+    #[prop_or_default]
+    pub selected: Option<T>,
+    /// Type palette of the radios. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub radio_palette: Palette,
+    /// Size of the radios. Default `Size::Medium`
+    #[prop_or(Size::Medium)]
+    pub radio_size: Size,
+    /// In which orientation will show the options. Default `Orientation::Vertical`
+    #[prop_or(Orientation::Vertical)]
+    pub orientation: Orientation,
+    /// Whether the group is disabled. Default `false`
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// An option must be selected for the form to be submittable. Default `false`
+    #[prop_or(false)]
+    pub required: bool,
+    /// Signal with the value of the option selected
+    #[prop_or(Callback::noop())]
+    pub onchange_signal: Callback<T>,
+    /// Error state for validation. Default `false`
+    #[prop_or(false)]
+    pub error_state: bool,
+    /// Show error message when error_state is true
+    #[prop_or_default]
+    pub error_message: String,
+    /// Form model that shows the errors of the field by `name`. Default `None`
+    #[prop_or_default]
+    pub form_model: Option<FormModel>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+}
+
+pub enum Msg {
+    Selected(usize),
+    KeyPressed(KeyboardEvent),
+}
+
+impl<T: Clone + PartialEq + ToString + 'static> Component for FormRadioGroup<T> {
+    type Message = Msg;
+    type Properties = Props<T>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
+        FormRadioGroup {
+            link,
+            selected: props.selected.clone(),
+            option_refs: vec![NodeRef::default(); props.options.len()],
+            props,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Selected(index) => self.select(index),
+            Msg::KeyPressed(keyboard_event) => {
+                let step = match keyboard_event.key().as_str() {
+                    "ArrowDown" => 1,
+                    "ArrowUp" => -1,
+                    "ArrowRight" => self.get_direction_sign(),
+                    "ArrowLeft" => -self.get_direction_sign(),
+                    _ => return false,
+                };
+
+                keyboard_event.prevent_default();
+
+                if self.props.disabled {
+                    return false;
+                }
+
+                let enabled = self
+                    .props
+                    .options
+                    .iter()
+                    .map(|option| !option.disabled)
+                    .collect::<Vec<bool>>();
+
+                match get_next_index(&enabled, self.get_selected_index(), step) {
+                    Some(index) => {
+                        if let Some(radio) = self.option_refs[index].cast::<HtmlElement>() {
+                            radio.focus().ok();
+                        }
+
+                        self.select(index)
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.selected != props.selected {
+                self.selected = props.selected.clone();
+            }
+
+            if self.option_refs.len() != props.options.len() {
+                self.option_refs = vec![NodeRef::default(); props.options.len()];
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let focusable_index = self.get_selected_index().or_else(|| {
+            self.props
+                .options
+                .iter()
+                .position(|option| !option.disabled)
+        });
+        let error = has_field_error(
+            &self.props.form_model,
+            &self.props.name,
+            self.props.error_state,
+        );
+
+        html! {
+            <>
+                <div
+                    class=classes!(
+                        "form-radio-group",
+                        get_palette(self.props.radio_palette.clone()),
+                        get_size(self.props.radio_size.clone()),
+                        match self.props.orientation {
+                            Orientation::Horizontal => "horizontal",
+                            Orientation::Vertical => "vertical",
+                        },
+                        StyleSource::from(get_custom_size_css(self.props.radio_size.clone(), |scale| {
+                            format!(
+                                "&.custom input {{ width: calc(16px * {scale}); height: calc(16px * {scale}); }}",
+                                scale = scale
+                            )
+                        })),
+                        StyleSource::from(get_choice_css(self.props.radio_palette.clone())),
+                        if error { "error" } else { "" },
+                        self.props.class_name.clone(),
+                        self.props.styles.clone()
+                    )
+                    role="radiogroup"
+                    aria-invalid=error.to_string()
+                    id=self.props.id.clone()
+                    key=self.props.key.clone()
+                    ref=self.props.code_ref.clone()
+                    onkeydown=self.link.callback(Msg::KeyPressed)
+                >
+                    {self.props.options.iter().enumerate().map(|(index, option)| {
+                        let disabled = self.props.disabled || option.disabled;
+
+                        html! {
+                            <label class=classes!("form-radio", if disabled { "disabled" } else { "" })>
+                                <input
+                                    type="radio"
+                                    ref=self.option_refs[index].clone()
+                                    name=self.props.name.clone()
+                                    value=option.value.to_string()
+                                    checked={self.selected.as_ref() == Some(&option.value)}
+                                    disabled=disabled
+                                    required=self.props.required
+                                    tabindex={if focusable_index == Some(index) { "0" } else { "-1" }}
+                                    onchange=self.link.callback(move |_| Msg::Selected(index))
+                                />
+                                <span class="form-radio-label">{option.label.clone()}</span>
+                            </label>
+                        }
+                    }).collect::<Html>()}
+                </div>
+                {get_field_error_message(
+                    &self.props.form_model,
+                    &self.props.name,
+                    self.props.error_state,
+                    self.props.error_message.clone()
+                )}
+            </>
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + ToString + 'static> FormRadioGroup<T> {
+    /// The horizontal arrows move backwards in right to left languages
+    fn get_direction_sign(&self) -> isize {
+        let rtl = self
+            .props
+            .code_ref
+            .cast::<HtmlElement>()
+            .and_then(|radio_group| window()?.get_computed_style(&radio_group).ok().flatten())
+            .and_then(|style| style.get_property_value("direction").ok())
+            .map_or(false, |direction| direction == "rtl");
+
+        if rtl {
+            -1
+        } else {
+            1
+        }
+    }
+
+    fn get_selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.props
+            .options
+            .iter()
+            .position(|option| option.value == *selected)
+    }
+
+    fn select(&mut self, index: usize) -> ShouldRender {
+        let value = match self.props.options.get(index) {
+            Some(option) => option.value.clone(),
+            None => return false,
+        };
+
+        if self.selected.as_ref() == Some(&value) {
+            return false;
+        }
+
+        self.selected = Some(value.clone());
+        self.props.onchange_signal.emit(value);
+
+        true
+    }
+}
+
+fn get_next_index(enabled: &[bool], current: Option<usize>, step: isize) -> Option<usize> {
+    let total = enabled.len() as isize;
+    let mut index = match current {
+        Some(current) => current as isize,
+        None if step > 0 => -1,
+        None => total,
+    };
+
+    for _ in 0..total {
+        index = (index + step).rem_euclid(total);
+
+        if enabled[index as usize] {
+            return Some(index as usize);
+        }
+    }
+
+    None
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_select_the_option_clicked() {
+    let body = utils::document().body().unwrap();
+    let element = utils::document().create_element("div").unwrap();
+    element.set_id("radio-selected");
+    body.append_child(&element).unwrap();
+
+    let props = Props::<u8> {
+        name: "quantity".to_string(),
+        options: vec![
+            RadioOption::new(1, "One"),
+            RadioOption::new(2, "Two"),
+            RadioOption::new(3, "Three"),
+        ],
+        selected: Some(1),
+        radio_palette: Palette::Primary,
+        radio_size: Size::Medium,
+        orientation: Orientation::Horizontal,
+        disabled: false,
+        required: false,
+        onchange_signal: Callback::from(|value: u8| {
+            utils::document()
+                .get_element_by_id("radio-selected")
+                .unwrap()
+                .set_text_content(Some(&value.to_string()));
+        }),
+        error_state: false,
+        error_message: "".to_string(),
+        form_model: None,
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "form-radio-group-test".to_string(),
+        id: "form-radio-group-id-test".to_string(),
+        styles: css!(""),
+    };
+
+    let form_radio_group: App<FormRadioGroup<u8>> = App::new();
+    form_radio_group.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let radios = utils::document()
+        .get_element_by_id("form-radio-group-id-test")
+        .unwrap()
+        .get_elements_by_tag_name("input");
+
+    assert_eq!(
+        radios.get_with_index(0).unwrap().get_attribute("tabindex"),
+        Some("0".to_string())
+    );
+
+    radios
+        .get_with_index(2)
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    let selected_element = utils::document()
+        .get_element_by_id("radio-selected")
+        .unwrap();
+
+    assert_eq!(selected_element.text_content().unwrap(), "3".to_string());
+    assert_eq!(
+        radios.get_with_index(2).unwrap().get_attribute("tabindex"),
+        Some("0".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_skip_the_disabled_options_with_the_arrow_keys() {
+    let enabled = vec![true, false, true, true];
+
+    assert_eq!(get_next_index(&enabled, Some(0), 1), Some(2));
+    assert_eq!(get_next_index(&enabled, Some(3), 1), Some(0));
+    assert_eq!(get_next_index(&enabled, Some(0), -1), Some(3));
+    assert_eq!(get_next_index(&enabled, None, 1), Some(0));
+    assert_eq!(get_next_index(&enabled, None, -1), Some(3));
+    assert_eq!(get_next_index(&[false, false], Some(0), 1), None);
+}
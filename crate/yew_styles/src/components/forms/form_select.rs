@@ -1,5 +1,8 @@
-use super::error_message::get_error_message;
+use super::error_message::{get_field_error_message, has_field_error};
+use super::form_model::FormModel;
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
 use crate::styles::{get_size, Size};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -121,6 +124,9 @@ pub struct Props {
     /// show error message when error_state is true.
     #[prop_or_default]
     pub error_message: String,
+    /// Form model that shows the errors of the field by `name`. Default `None`
+    #[prop_or_default]
+    pub form_model: Option<FormModel>,
     /// general property to add custom id
     #[prop_or_default]
     pub id: String,
@@ -138,6 +144,8 @@ impl Component for FormSelect {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
         Self { link, props }
     }
 
@@ -160,12 +168,22 @@ impl Component for FormSelect {
     }
 
     fn view(&self) -> Html {
+        let error = has_field_error(
+            &self.props.form_model,
+            &self.props.name,
+            self.props.error_state,
+        );
+
         html! {
             <>
                 <select
                     class=classes!(
                         "form-select",
                         get_size(self.props.select_size.clone()),
+                        StyleSource::from(get_custom_size_css(self.props.select_size.clone(), |scale| {
+                            format!("&.custom {{ padding: calc(3px * {}); }}", scale)
+                        })),
+                        if error { "error" } else { "" },
                         self.props.class_name.clone(),
                         self.props.styles.clone()
                     )
@@ -179,10 +197,16 @@ impl Component for FormSelect {
                     multiple=self.props.multiple
                     size=self.props.size.to_string()
                     onchange=self.link.callback(Msg::Selected)
+                    aria-invalid=error.to_string()
                 >
                     {self.props.options.clone()}
                 </select>
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_field_error_message(
+                    &self.props.form_model,
+                    &self.props.name,
+                    self.props.error_state,
+                    self.props.error_message.clone()
+                )}
             </>
         }
     }
@@ -204,6 +228,7 @@ fn should_create_form_select() {
         name: "options".to_string(),
         error_message: "".to_string(),
         error_state: false,
+        form_model: None,
         multiple: false,
         styles: css!("background-color: #918d94;"),
         options: html! {
@@ -0,0 +1,52 @@
+use crate::styles::{Palette, Style};
+use crate::theme::{get_theme_color, ThemeColor};
+
+pub fn get_form_css(palette: Palette, underline: bool) -> String {
+    let border_color = get_theme_color(palette.clone(), Style::Outline, ThemeColor::BorderColor);
+
+    format!(
+        "{}
+        &::placeholder {{
+            color: {};
+        }}
+        &:focus {{
+            border-color: {};
+        }}
+        &:hover {{
+            border-color: {};
+        }}
+        &:active {{
+            border-color: {};
+        }}",
+        if underline {
+            format!(
+                "border-radius: 2px;
+                border-top: 0;
+                border-left: 0;
+                border-right: 0;
+                border-bottom: 2px solid {};",
+                border_color
+            )
+        } else {
+            format!("border: 1px solid {};", border_color)
+        },
+        get_theme_color(palette.clone(), Style::Outline, ThemeColor::Color),
+        get_theme_color(palette.clone(), Style::Outline, ThemeColor::BorderFocus),
+        get_theme_color(palette.clone(), Style::Outline, ThemeColor::BorderHover),
+        get_theme_color(palette, Style::Outline, ThemeColor::BorderActive),
+    )
+}
+
+pub fn get_choice_css(palette: Palette) -> String {
+    format!(
+        "input {{
+            accent-color: {};
+        }}
+        input:focus-visible {{
+            outline: 2px solid {};
+            outline-offset: 2px;
+        }}",
+        get_theme_color(palette.clone(), Style::Regular, ThemeColor::Background),
+        get_theme_color(palette, Style::Outline, ThemeColor::BorderFocus),
+    )
+}
@@ -1,4 +1,7 @@
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
+use crate::spinner::{get_inline_spinner, SpinnerType};
 use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::theme::{get_font_size_css, get_palette_css};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -31,6 +34,12 @@ pub struct Props {
     /// Whether the form control is disabled. Default `false`
     #[prop_or(false)]
     pub disabled: bool,
+    /// Show a spinner instead of the text and block the submit, keeping the width of the submit. Default `false`
+    #[prop_or(false)]
+    pub loading: bool,
+    /// Spinner type showed when the submit is loading. Default `SpinnerType::Circle`
+    #[prop_or(SpinnerType::Circle)]
+    pub loading_spinner: SpinnerType,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -52,6 +61,8 @@ impl Component for FormSubmit {
     type Properties = Props;
 
     fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
         Self { props }
     }
 
@@ -70,20 +81,39 @@ impl Component for FormSubmit {
 
     fn view(&self) -> Html {
         html! {
-            <input
+            <button
                 type="submit"
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
                 class=classes!(
                     "form-submit",
+                    if self.props.loading { "loading" } else { "" },
                     get_style(self.props.submit_style.clone()),
                     get_palette(self.props.submit_palette.clone()),
                     get_size(self.props.size.clone()),
+                    StyleSource::from(format!(
+                        "{}{}",
+                        get_palette_css(
+                            self.props.submit_palette.clone(),
+                            self.props.submit_style.clone(),
+                            true
+                        ),
+                        get_font_size_css(self.props.size.clone())
+                    )),
                 self.props.class_name.clone())
-                disabled=self.props.disabled
+                disabled={self.props.disabled || self.props.loading}
+                aria-busy=self.props.loading.to_string()
                 id=self.props.id.clone()
                 value=self.props.value.clone()
-            />
+            >
+                <span class="button-content">{self.props.value.clone()}</span>
+                {get_inline_spinner(
+                    self.props.loading,
+                    self.props.loading_spinner.clone(),
+                    self.props.submit_palette.clone(),
+                    self.props.submit_style.clone()
+                )}
+            </button>
         }
     }
 }
@@ -93,6 +123,8 @@ fn should_create_form_submit() {
     let props = Props {
         value: "submit".to_string(),
         disabled: false,
+        loading: false,
+        loading_spinner: SpinnerType::Circle,
         key: "".to_string(),
         code_ref: NodeRef::default(),
         id: "result".to_string(),
@@ -112,5 +144,5 @@ fn should_create_form_submit() {
 
     let form_submit_element = utils::document().get_element_by_id("result").unwrap();
 
-    assert_eq!(form_submit_element.tag_name(), "INPUT");
+    assert_eq!(form_submit_element.tag_name(), "BUTTON");
 }
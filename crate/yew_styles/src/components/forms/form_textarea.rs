@@ -1,5 +1,9 @@
-use super::error_message::get_error_message;
+use super::error_message::{get_field_error_message, has_field_error};
+use super::form_model::FormModel;
+use super::form_styles::get_form_css;
+use crate::embedded_styles::{mount_styles, FORMS_STYLES};
 use crate::styles::{get_palette, get_size, Palette, Size};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -143,6 +147,9 @@ pub struct Props {
     /// Show error message when error_state is true
     #[prop_or_default]
     pub error_message: String,
+    /// Form model that shows the errors of the field by `name`. Default `None`
+    #[prop_or_default]
+    pub form_model: Option<FormModel>,
     /// Indicates how the control wraps text. Default `WrapText::Soft`
     #[prop_or(WrapText::Soft)]
     pub wrap: WrapText,
@@ -163,6 +170,8 @@ impl Component for FormTextArea {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(FORMS_STYLES);
+
         Self { link, props }
     }
 
@@ -192,6 +201,12 @@ impl Component for FormTextArea {
     }
 
     fn view(&self) -> Html {
+        let error = has_field_error(
+            &self.props.form_model,
+            &self.props.name,
+            self.props.error_state,
+        );
+
         html! {
             <>
                 <textarea
@@ -199,6 +214,14 @@ impl Component for FormTextArea {
                     class=classes!("form-textarea",
                         get_palette(self.props.textarea_style.clone()),
                         get_size(self.props.textarea_size.clone()),
+                        StyleSource::from(get_custom_size_css(self.props.textarea_size.clone(), |scale| {
+                            format!("&.custom {{ height: calc(100px * {}); }}", scale)
+                        })),
+                        StyleSource::from(get_form_css(
+                            self.props.textarea_style.clone(),
+                            false
+                        )),
+                        if error { "error" } else { "" },
                         self.props.class_name.clone(),
                         self.props.styles.clone()
                     )
@@ -220,8 +243,14 @@ impl Component for FormTextArea {
                     minlength=self.props.minlength.to_string()
                     maxlength=self.props.maxlength.to_string()
                     warp=get_wrap(self.props.wrap.clone())
+                    aria-invalid=error.to_string()
                 />
-                {get_error_message(self.props.error_state, self.props.error_message.clone())}
+                {get_field_error_message(
+                    &self.props.form_model,
+                    &self.props.name,
+                    self.props.error_state,
+                    self.props.error_message.clone()
+                )}
             </>
         }
     }
@@ -248,6 +277,7 @@ fn should_create_form_textarea() {
         onkeydown_signal: Callback::noop(),
         error_message: "invalid input".to_string(),
         error_state: false,
+        form_model: None,
         name: "input-test".to_string(),
         textarea_style: Palette::Standard,
         textarea_size: Size::Medium,
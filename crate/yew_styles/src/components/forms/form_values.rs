@@ -0,0 +1,527 @@
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use std::cell::RefCell;
+use std::fmt;
+use std::str::FromStr;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{
+    HtmlFormElement, HtmlInputElement, HtmlOptionElement, HtmlSelectElement, HtmlTextAreaElement,
+};
+
+/// # Form Values
+///
+/// Values of the form controls grouped by `name`. `Form` collects them on submit and deserializes
+/// them into the type of `onsubmit_values_signal`, the same way that it works with the query
+/// strings: unchecked checkboxes and radios are not included, the selected options of a multiple
+/// select are a sequence and an empty or missing value is `None` in an `Option` field. Any other
+/// missing field is an error unless it has `#[serde(default)]`, like a `bool` of a checkbox.
+/// The file inputs are not included
+///
+/// ## Features required
+///
+/// forms
+///
+/// ## Example
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use yew_styles::forms::form_values::FormValues;
+///
+/// #[derive(Deserialize)]
+/// struct Filter {
+///     search: String,
+///     page: u32,
+///     tags: Vec<String>,
+///     #[serde(default)]
+///     archived: bool,
+/// }
+///
+/// let mut form_values = FormValues::new();
+/// form_values.append("search", "yew");
+/// form_values.append("page", "2");
+/// form_values.append("tags", "rust");
+/// form_values.append("tags", "wasm");
+///
+/// let filter: Filter = form_values.deserialize().unwrap();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormValues {
+    values: Vec<(String, Vec<String>)>,
+}
+
+/// Error of one field after deserialize the form values
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldError {
+    /// Name of the field. Empty when the error is not related with one field
+    pub name: String,
+    /// Description of the error
+    pub message: String,
+}
+
+impl FormValues {
+    pub fn new() -> Self {
+        FormValues { values: vec![] }
+    }
+
+    /// Collects the values of the named and enabled controls of the form
+    pub fn from_form(form: &HtmlFormElement) -> Self {
+        let mut form_values = FormValues::new();
+        let elements = form.elements();
+
+        for index in 0..elements.length() {
+            let element = match elements.item(index) {
+                Some(element) => element,
+                None => continue,
+            };
+
+            if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+                let collected = match input.type_().as_str() {
+                    "checkbox" | "radio" => input.checked(),
+                    "file" | "submit" | "button" | "reset" | "image" => false,
+                    _ => true,
+                };
+
+                if collected && !input.disabled() {
+                    form_values.append(&input.name(), &input.value());
+                }
+            } else if let Some(select) = element.dyn_ref::<HtmlSelectElement>() {
+                if select.disabled() {
+                    continue;
+                }
+
+                let options = select.selected_options();
+
+                for option_index in 0..options.length() {
+                    if let Some(option) = options
+                        .item(option_index)
+                        .and_then(|option| option.dyn_into::<HtmlOptionElement>().ok())
+                    {
+                        form_values.append(&select.name(), &option.value());
+                    }
+                }
+            } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+                if !textarea.disabled() {
+                    form_values.append(&textarea.name(), &textarea.value());
+                }
+            }
+        }
+
+        form_values
+    }
+
+    /// Adds a value to the field, the controls without name are ignored
+    pub fn append(&mut self, name: &str, value: &str) {
+        if name.is_empty() {
+            return;
+        }
+
+        match self.values.iter_mut().find(|(field, _)| field == name) {
+            Some((_, values)) => values.push(value.to_string()),
+            None => self
+                .values
+                .push((name.to_string(), vec![value.to_string()])),
+        }
+    }
+
+    /// All the values of the field
+    pub fn get(&self, name: &str) -> Option<&[String]> {
+        self.values
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, values)| values.as_slice())
+    }
+
+    /// Deserializes the values in `T` matching the fields by name.
+    /// It returns the errors of every field that could not be deserialized
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, Vec<FieldError>> {
+        let errors = RefCell::new(vec![]);
+        let result = T::deserialize(FormDeserializer {
+            form_values: self,
+            errors: &errors,
+        });
+        let mut errors = errors.into_inner();
+
+        match result {
+            Ok(value) if errors.is_empty() => Ok(value),
+            Ok(_) => Err(errors),
+            Err(error) => {
+                let field_error = FieldError {
+                    name: error.name,
+                    message: error.message,
+                };
+
+                // The errors of the fields are already added
+                if !errors.contains(&field_error) {
+                    errors.push(field_error);
+                }
+
+                Err(errors)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DeError {
+    name: String,
+    message: String,
+}
+
+impl fmt::Display for DeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        DeError {
+            name: String::new(),
+            message: msg.to_string(),
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        DeError {
+            name: field.to_string(),
+            message: "missing field".to_string(),
+        }
+    }
+}
+
+struct FormDeserializer<'a> {
+    form_values: &'a FormValues,
+    errors: &'a RefCell<Vec<FieldError>>,
+}
+
+impl<'de, 'a> de::Deserializer<'de> for FormDeserializer<'a> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let names = self
+            .form_values
+            .values
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<String>>();
+
+        visitor.visit_map(FieldsAccess {
+            names: names.into_iter(),
+            current: None,
+            form_values: self.form_values,
+            errors: self.errors,
+        })
+    }
+
+    // A form without typed values, like `Form<()>`, ignores the values
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct FieldsAccess<'a> {
+    names: std::vec::IntoIter<String>,
+    current: Option<String>,
+    form_values: &'a FormValues,
+    errors: &'a RefCell<Vec<FieldError>>,
+}
+
+impl<'de, 'a> MapAccess<'de> for FieldsAccess<'a> {
+    type Error = DeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, DeError> {
+        match self.names.next() {
+            Some(name) => {
+                self.current = Some(name.clone());
+                seed.deserialize(name.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, DeError> {
+        let name = self.current.take().unwrap_or_default();
+
+        seed.deserialize(FieldDeserializer {
+            values: self.form_values.get(&name).unwrap_or(&[]),
+            name,
+            errors: self.errors,
+        })
+    }
+}
+
+struct FieldDeserializer<'a> {
+    name: String,
+    values: &'a [String],
+    errors: &'a RefCell<Vec<FieldError>>,
+}
+
+impl<'a> FieldDeserializer<'a> {
+    fn get_value(&self) -> Option<&'a String> {
+        self.values.last()
+    }
+
+    fn add_error(&self, message: String) -> DeError {
+        self.errors.borrow_mut().push(FieldError {
+            name: self.name.clone(),
+            message: message.clone(),
+        });
+
+        DeError {
+            name: self.name.clone(),
+            message,
+        }
+    }
+
+    fn check<T>(&self, result: Result<T, DeError>) -> Result<T, DeError> {
+        result.map_err(|error| self.add_error(error.message))
+    }
+
+    /// Invalid or missing values add an error and return the default value
+    /// to continue with the rest of the fields
+    fn parse<T: FromStr + Default>(&self) -> T {
+        match self.get_value() {
+            Some(value) => value.trim().parse::<T>().unwrap_or_else(|_| {
+                self.add_error(format!("`{}` is not a valid value", value));
+                T::default()
+            }),
+            None => {
+                self.add_error("missing field".to_string());
+                T::default()
+            }
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                let value = self.parse();
+                self.check(visitor.$visit(value))
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> de::Deserializer<'de> for FieldDeserializer<'a> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.get_value() {
+            Some(value) => self.check(visitor.visit_string(value.clone())),
+            None => self.check(visitor.visit_none()),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let value = match self.get_value().map(|value| value.as_str()) {
+            None | Some("") | Some("false") | Some("off") | Some("0") => false,
+            Some("true") | Some("on") | Some("1") => true,
+            Some(value) => {
+                self.add_error(format!("`{}` is not a valid value", value));
+                false
+            }
+        };
+
+        self.check(visitor.visit_bool(value))
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.get_value() {
+            Some(value) => self.check(visitor.visit_string(value.clone())),
+            None => {
+                self.add_error("missing field".to_string());
+                self.check(visitor.visit_string(String::new()))
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.get_value() {
+            None => visitor.visit_none(),
+            Some(value) if value.is_empty() => visitor.visit_none(),
+            Some(_) => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_seq(ValuesAccess {
+            name: self.name.clone(),
+            values: self.values.iter(),
+            errors: self.errors,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        match self.get_value() {
+            Some(value) => self.check(visitor.visit_enum(value.clone().into_deserializer())),
+            None => Err(self.add_error("missing field".to_string())),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bytes byte_buf unit_struct tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ValuesAccess<'a> {
+    name: String,
+    values: std::slice::Iter<'a, String>,
+    errors: &'a RefCell<Vec<FieldError>>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for ValuesAccess<'a> {
+    type Error = DeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, DeError> {
+        match self.values.next() {
+            Some(value) => seed
+                .deserialize(FieldDeserializer {
+                    name: self.name.clone(),
+                    values: std::slice::from_ref(value),
+                    errors: self.errors,
+                })
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(serde::Deserialize, Debug, PartialEq)]
+struct Application {
+    name: String,
+    age: u8,
+    #[serde(default)]
+    skills: Vec<String>,
+    #[serde(default)]
+    newsletter: bool,
+    website: Option<String>,
+}
+
+#[wasm_bindgen_test]
+fn should_deserialize_the_form_values() {
+    let mut form_values = FormValues::new();
+    form_values.append("name", "Spielrs");
+    form_values.append("age", "30");
+    form_values.append("skills", "yew");
+    form_values.append("skills", "rust");
+    form_values.append("website", "");
+    form_values.append("", "ignored");
+
+    let application: Application = form_values.deserialize().unwrap();
+
+    assert_eq!(
+        application,
+        Application {
+            name: "Spielrs".to_string(),
+            age: 30,
+            skills: vec!["yew".to_string(), "rust".to_string()],
+            newsletter: false,
+            website: None,
+        }
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_return_the_errors_of_the_fields() {
+    let mut form_values = FormValues::new();
+    form_values.append("age", "thirty");
+    form_values.append("newsletter", "on");
+
+    let errors = form_values.deserialize::<Application>().unwrap_err();
+
+    assert_eq!(
+        errors,
+        vec![
+            FieldError {
+                name: "age".to_string(),
+                message: "`thirty` is not a valid value".to_string(),
+            },
+            FieldError {
+                name: "name".to_string(),
+                message: "missing field".to_string(),
+            },
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_ignore_the_form_values_without_type() {
+    let mut form_values = FormValues::new();
+    form_values.append("name", "Spielrs");
+
+    assert_eq!(form_values.deserialize::<()>(), Ok(()));
+}
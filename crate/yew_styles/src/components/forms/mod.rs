@@ -1,9 +1,14 @@
 mod error_message;
+pub mod form_checkbox;
 pub mod form_component;
 pub mod form_file;
 pub mod form_group;
 pub mod form_input;
 pub mod form_label;
+pub mod form_model;
+pub mod form_radio_group;
 pub mod form_select;
+mod form_styles;
 pub mod form_submit;
 pub mod form_textarea;
+pub mod form_values;
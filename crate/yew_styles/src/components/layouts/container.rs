@@ -1,4 +1,6 @@
-use crate::utils::{create_style, get_random_string};
+use crate::embedded_styles::{mount_styles, LAYOUTS_STYLES};
+use crate::styles::Size;
+use crate::theme::{get_responsive_css, get_spacing_css, Breakpoints, Responsive};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -68,7 +70,6 @@ use yew::{utils, App};
 /// ```
 pub struct Container {
     props: Props,
-    pub key: String,
 }
 
 #[derive(Clone, Copy)]
@@ -109,7 +110,9 @@ pub enum JustifyContent {
     SpaceEvenly(Mode),
     Start(Mode),
     End(Mode),
+    /// Left in left to right direction and right in right to left
     Left(Mode),
+    /// Right in left to right direction and left in right to left
     Rigth(Mode),
 }
 
@@ -150,9 +153,11 @@ pub enum Msg {}
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
-    /// Which direction are placing the items. Required
+    /// Which direction are placing the items. Default `Direction::Row`
+    #[prop_or(Direction::Row)]
     pub direction: Direction,
-    /// Set a wrap for the items. Required
+    /// Set a wrap for the items. Default `Wrap::Wrap`
+    #[prop_or(Wrap::Wrap)]
     pub wrap: Wrap,
     /// Set how will be justified the content. Default `JustifyContent::FlexStart(Mode::NoMode)`
     #[prop_or(JustifyContent::FlexStart(Mode::NoMode))]
@@ -163,6 +168,37 @@ pub struct Props {
     /// Set how will be aligned the items. Default `AlignItems::Stretch(Mode::NoMode)`
     #[prop_or(AlignItems::Stretch(Mode::NoMode))]
     pub align_items: AlignItems,
+    /// Direction of the items from the breakpoint of each screen, it overrides `direction`. Default `vec![]`
+    #[prop_or_default]
+    pub responsive_direction: Vec<Responsive<Direction>>,
+    /// Wrap of the items from the breakpoint of each screen, it overrides `wrap`. Default `vec![]`
+    #[prop_or_default]
+    pub responsive_wrap: Vec<Responsive<Wrap>>,
+    /// Justify content from the breakpoint of each screen, it overrides `justify_content`. Default `vec![]`
+    #[prop_or_default]
+    pub responsive_justify_content: Vec<Responsive<JustifyContent>>,
+    /// Align items from the breakpoint of each screen, it overrides `align_items`. Default `vec![]`
+    #[prop_or_default]
+    pub responsive_align_items: Vec<Responsive<AlignItems>>,
+    /// Space between the rows and the columns in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub gap: Vec<Responsive<Size>>,
+    /// Space between the rows in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub row_gap: Vec<Responsive<Size>>,
+    /// Space between the columns in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub column_gap: Vec<Responsive<Size>>,
+    /// Space inside of the component in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub padding: Vec<Responsive<Size>>,
+    /// Space around the component in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub margin: Vec<Responsive<Size>>,
+    /// Min width in pixels of each screen for the responsive props.
+    /// Default `Breakpoints::default()`
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -185,15 +221,9 @@ impl Component for Container {
     type Properties = Props;
 
     fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
-        let key = get_random_string(10);
+        mount_styles(LAYOUTS_STYLES);
 
-        Container { props, key }
-    }
-
-    fn rendered(&mut self, first_render: bool) {
-        if first_render {
-            ContainerModel.init(self.props.clone(), self.key.clone());
-        }
+        Container { props }
     }
 
     fn update(&mut self, _msg: Self::Message) -> ShouldRender {
@@ -211,7 +241,12 @@ impl Component for Container {
 
     fn view(&self) -> Html {
         html! {
-            <div class=classes!("container", format!("container-{} {}", self.key, self.props.class_name), self.props.styles.clone())
+            <div class=classes!(
+                    "container",
+                    self.props.class_name.clone(),
+                    StyleSource::from(ContainerModel.get_container_css(self.props.clone())),
+                    self.props.styles.clone()
+                )
                 id=self.props.id.to_string()
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
@@ -223,34 +258,65 @@ impl Component for Container {
 }
 
 impl ContainerModel {
-    fn init(self, props: Props, key: String) {
-        self.get_flow(props.direction, props.wrap, key.clone());
-        self.get_justify_content(props.justify_content, key.clone());
-        self.get_align_content(props.align_content, key.clone());
-        self.get_align_items(props.align_items, key);
+    fn get_container_css(self, props: Props) -> String {
+        format!(
+            "flex-flow: {};
+            justify-content: {};
+            align-content: {};
+            align-items: {};\n{}{}{}{}{}{}{}{}{}",
+            self.get_flow(props.direction, props.wrap),
+            self.get_justify_content(props.justify_content),
+            self.get_align_content(props.align_content),
+            self.get_align_items(props.align_items),
+            get_responsive_css(
+                &props.breakpoints,
+                props.responsive_direction,
+                |direction| format!("flex-direction: {};", self.get_direction(direction))
+            ),
+            get_responsive_css(&props.breakpoints, props.responsive_wrap, |wrap| format!(
+                "flex-wrap: {};",
+                self.get_wrap(wrap)
+            )),
+            get_responsive_css(
+                &props.breakpoints,
+                props.responsive_justify_content,
+                |justify_content| format!(
+                    "justify-content: {};",
+                    self.get_justify_content(justify_content)
+                )
+            ),
+            get_responsive_css(
+                &props.breakpoints,
+                props.responsive_align_items,
+                |align_items| format!("align-items: {};", self.get_align_items(align_items))
+            ),
+            get_spacing_css(&props.breakpoints, "gap", props.gap),
+            get_spacing_css(&props.breakpoints, "row-gap", props.row_gap),
+            get_spacing_css(&props.breakpoints, "column-gap", props.column_gap),
+            get_spacing_css(&props.breakpoints, "padding", props.padding),
+            get_spacing_css(&props.breakpoints, "margin", props.margin),
+        )
     }
 
-    fn get_flow(self, direction: Direction, wrap: Wrap, key: String) {
-        let direction = match direction {
+    fn get_flow(self, direction: Direction, wrap: Wrap) -> String {
+        format!("{} {}", self.get_direction(direction), self.get_wrap(wrap))
+    }
+
+    fn get_direction(self, direction: Direction) -> String {
+        match direction {
             Direction::Row => "row".to_string(),
             Direction::RowReverse => "row-reverse".to_string(),
             Direction::Column => "column".to_string(),
             Direction::ColumnReverse => "column-reverse".to_string(),
-        };
+        }
+    }
 
-        let wrap = match wrap {
+    fn get_wrap(self, wrap: Wrap) -> String {
+        match wrap {
             Wrap::Nowrap => "nowrap".to_string(),
             Wrap::Wrap => "wrap".to_string(),
             Wrap::WrapReverse => "wrap-reverse".to_string(),
-        };
-
-        let value = format!("{} {}", direction, wrap);
-
-        create_style(
-            String::from("flex-flow"),
-            value,
-            format!("container-{}", key),
-        );
+        }
     }
 
     fn get_mode(self, mode: Mode) -> String {
@@ -261,29 +327,23 @@ impl ContainerModel {
         }
     }
 
-    fn get_justify_content(self, justify_content: JustifyContent, key: String) {
-        let value = match justify_content {
+    fn get_justify_content(self, justify_content: JustifyContent) -> String {
+        match justify_content {
             JustifyContent::FlexStart(mode) => format!("flex-start{}", self.get_mode(mode)),
             JustifyContent::FlexEnd(mode) => format!("flex-end{}", self.get_mode(mode)),
             JustifyContent::Start(mode) => format!("start{}", self.get_mode(mode)),
             JustifyContent::End(mode) => format!("end{}", self.get_mode(mode)),
-            JustifyContent::Left(mode) => format!("left{}", self.get_mode(mode)),
+            JustifyContent::Left(mode) => format!("start{}", self.get_mode(mode)),
             JustifyContent::Center(mode) => format!("center{}", self.get_mode(mode)),
-            JustifyContent::Rigth(mode) => format!("right{}", self.get_mode(mode)),
+            JustifyContent::Rigth(mode) => format!("end{}", self.get_mode(mode)),
             JustifyContent::SpaceAround(mode) => format!("space-around{}", self.get_mode(mode)),
             JustifyContent::SpaceBetween(mode) => format!("space-between{}", self.get_mode(mode)),
-            JustifyContent::SpaceEvenly(mode) => format!("evenly{}", self.get_mode(mode)),
-        };
-
-        create_style(
-            String::from("justify-content"),
-            value,
-            format!("container-{}", key),
-        );
+            JustifyContent::SpaceEvenly(mode) => format!("space-evenly{}", self.get_mode(mode)),
+        }
     }
 
-    fn get_align_content(self, align_content: AlignContent, key: String) {
-        let value = match align_content {
+    fn get_align_content(self, align_content: AlignContent) -> String {
+        match align_content {
             AlignContent::Stretch(mode) => format!("stretch{}", self.get_mode(mode)),
             AlignContent::FlexStart(mode) => format!("flex-start{}", self.get_mode(mode)),
             AlignContent::FlexEnd(mode) => format!("flex-end{}", self.get_mode(mode)),
@@ -295,36 +355,24 @@ impl ContainerModel {
             AlignContent::LastBaseline(mode) => format!("last-baseline{}", self.get_mode(mode)),
             AlignContent::SpaceAround(mode) => format!("space-around{}", self.get_mode(mode)),
             AlignContent::SpaceBetween(mode) => format!("space-between{}", self.get_mode(mode)),
-            AlignContent::SpaceEvenly(mode) => format!("evenly{}", self.get_mode(mode)),
-        };
-
-        create_style(
-            String::from("align-content"),
-            value,
-            format!("container-{}", key),
-        );
+            AlignContent::SpaceEvenly(mode) => format!("space-evenly{}", self.get_mode(mode)),
+        }
     }
 
-    fn get_align_items(self, align_items: AlignItems, key: String) {
-        let value = match align_items {
+    fn get_align_items(self, align_items: AlignItems) -> String {
+        match align_items {
             AlignItems::Stretch(mode) => format!("stretch{}", self.get_mode(mode)),
             AlignItems::Baseline(mode) => format!("baseline{}", self.get_mode(mode)),
             AlignItems::Start(mode) => format!("start{}", self.get_mode(mode)),
             AlignItems::End(mode) => format!("end{}", self.get_mode(mode)),
-            AlignItems::FlexStart(mode) => format!("start{}", self.get_mode(mode)),
+            AlignItems::FlexStart(mode) => format!("flex-start{}", self.get_mode(mode)),
             AlignItems::FlexEnd(mode) => format!("flex-end{}", self.get_mode(mode)),
             AlignItems::FirstBaseline(mode) => format!("first-baseline{}", self.get_mode(mode)),
             AlignItems::LastBaseline(mode) => format!("last-baseline{}", self.get_mode(mode)),
             AlignItems::SelfStart(mode) => format!("self-start{}", self.get_mode(mode)),
             AlignItems::SelfEnd(mode) => format!("self-end{}", self.get_mode(mode)),
             AlignItems::Center(mode) => format!("center{}", self.get_mode(mode)),
-        };
-
-        create_style(
-            String::from("align-items"),
-            value,
-            format!("container-{}", key),
-        );
+        }
     }
 }
 
@@ -338,7 +386,17 @@ fn should_create_a_container() {
         justify_content: JustifyContent::Center(Mode::NoMode),
         align_content: AlignContent::Center(Mode::NoMode),
         align_items: AlignItems::Center(Mode::NoMode),
+        responsive_direction: vec![],
+        responsive_wrap: vec![],
+        responsive_justify_content: vec![],
+        responsive_align_items: vec![],
+        gap: vec![],
+        row_gap: vec![],
+        column_gap: vec![],
+        padding: vec![],
+        margin: vec![],
         key: "".to_string(),
+        breakpoints: Breakpoints::default(),
         code_ref: NodeRef::default(),
         class_name: String::from("layout-test"),
         styles: css!("color: red;"),
@@ -361,3 +419,83 @@ fn should_create_a_container() {
         "Container".to_string()
     );
 }
+
+#[wasm_bindgen_test]
+fn should_get_container_css_from_props() {
+    let props_container = Props {
+        direction: Direction::Column,
+        wrap: Wrap::Nowrap,
+        justify_content: JustifyContent::SpaceEvenly(Mode::SafeMode),
+        align_content: AlignContent::Center(Mode::NoMode),
+        align_items: AlignItems::FlexStart(Mode::NoMode),
+        responsive_direction: vec![],
+        responsive_wrap: vec![],
+        responsive_justify_content: vec![],
+        responsive_align_items: vec![],
+        gap: vec![Responsive::Xs(Size::Small), Responsive::L(Size::Big)],
+        row_gap: vec![],
+        column_gap: vec![],
+        padding: vec![],
+        margin: vec![],
+        key: "".to_string(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        class_name: String::from("layout-test"),
+        styles: css!("color: red;"),
+        id: String::from("layout-id-test"),
+        children: Children::new(vec![]),
+    };
+
+    assert_eq!(
+        ContainerModel.get_container_css(props_container),
+        "flex-flow: column nowrap;
+            justify-content: space-evenly safe;
+            align-content: center;
+            align-items: flex-start;
+gap: var(--ys-spacing-small, 8px);
+@media (min-width: 992px) { gap: var(--ys-spacing-big, 24px); }
+"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_responsive_container_css() {
+    let props_container = Props {
+        direction: Direction::Row,
+        wrap: Wrap::Wrap,
+        justify_content: JustifyContent::FlexStart(Mode::NoMode),
+        align_content: AlignContent::Stretch(Mode::NoMode),
+        align_items: AlignItems::Stretch(Mode::NoMode),
+        responsive_direction: vec![
+            Responsive::M(Direction::Row),
+            Responsive::Xs(Direction::Column),
+        ],
+        responsive_wrap: vec![],
+        responsive_justify_content: vec![Responsive::M(JustifyContent::SpaceBetween(Mode::NoMode))],
+        responsive_align_items: vec![],
+        gap: vec![],
+        row_gap: vec![],
+        column_gap: vec![],
+        padding: vec![],
+        margin: vec![],
+        key: "".to_string(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        class_name: String::from("layout-test"),
+        styles: css!("color: red;"),
+        id: String::from("layout-id-test"),
+        children: Children::new(vec![]),
+    };
+
+    assert_eq!(
+        ContainerModel.get_container_css(props_container),
+        "flex-flow: row wrap;
+            justify-content: flex-start;
+            align-content: stretch;
+            align-items: stretch;
+flex-direction: column;
+@media (min-width: 768px) { flex-direction: row; }
+@media (min-width: 768px) { justify-content: space-between; }
+"
+    );
+}
@@ -0,0 +1,432 @@
+use crate::embedded_styles::{mount_styles, LAYOUTS_STYLES};
+use crate::styles::Size;
+use crate::theme::{get_responsive_css, get_spacing_css, Breakpoints, Responsive};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Grid component
+///
+/// ## Features required
+///
+/// layouts
+///
+/// ## Example
+///
+/// The grid in yew styles is base in css grid
+/// you can fine more information about the properties options
+/// [here](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Grid_Layout/Basic_Concepts_of_Grid_Layout)
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::{
+///     layouts::{
+///         grid::{Grid, GridTemplate, GridTrack},
+///         grid_item::{GridItem, GridSpan},
+///     },
+///     theme::Responsive,
+/// };
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+///         App {}
+///     }
+///
+///     fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///        html! {
+///          <Grid
+///             columns=vec![
+///                 Responsive::Xs(GridTemplate::Repeat(1, GridTrack::Fr(1.0))),
+///                 Responsive::M(GridTemplate::Repeat(3, GridTrack::Fr(1.0))),
+///             ]
+///          >
+///                <GridItem column=vec![Responsive::M(GridSpan::Span(2))]>
+///                    <h3>{"chart"}</h3>
+///                </GridItem>
+///                <GridItem>
+///                    <h3>{"summary"}</h3>
+///                </GridItem>
+///           </Grid>
+///        }
+///     }
+/// }
+/// ```
+pub struct Grid {
+    props: Props,
+}
+
+#[derive(Clone, Copy)]
+struct GridModel;
+
+/// Size of a track of the grid
+#[derive(Clone, PartialEq)]
+pub enum GridTrack {
+    Fr(f64),
+    Px(f64),
+    Percent(f64),
+    Auto,
+    MinContent,
+    MaxContent,
+    /// Size between the min and the max track
+    MinMax(Box<GridTrack>, Box<GridTrack>),
+}
+
+/// Set how will be created the tracks of the grid
+#[derive(Clone, PartialEq)]
+pub enum GridTemplate {
+    None,
+    Tracks(Vec<GridTrack>),
+    /// Repeat the track a number of times, at least once
+    Repeat(u16, GridTrack),
+    /// Repeat the track as many times as it fits, keeping the empty tracks
+    AutoFill(GridTrack),
+    /// Repeat the track as many times as it fits, collapsing the empty tracks
+    AutoFit(GridTrack),
+}
+
+/// Named areas of the grid, one string for each row. Example `"header header"`.
+/// Only letters, numbers, `-`, `_` and `.` are kept in the names
+#[derive(Clone, PartialEq)]
+pub enum GridAreas {
+    None,
+    Rows(Vec<String>),
+}
+
+/// Set how will be placed the items without position
+#[derive(Clone, PartialEq)]
+pub enum AutoFlow {
+    Row,
+    Column,
+    RowDense,
+    ColumnDense,
+}
+
+pub enum Msg {}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Tracks of the columns in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub columns: Vec<Responsive<GridTemplate>>,
+    /// Tracks of the rows in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub rows: Vec<Responsive<GridTemplate>>,
+    /// Named areas of the grid in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub areas: Vec<Responsive<GridAreas>>,
+    /// Set how will be placed the items without position. Default `AutoFlow::Row`
+    #[prop_or(AutoFlow::Row)]
+    pub auto_flow: AutoFlow,
+    /// Space between the rows and the columns in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub gap: Vec<Responsive<Size>>,
+    /// Space between the rows in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub row_gap: Vec<Responsive<Size>>,
+    /// Space between the columns in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub column_gap: Vec<Responsive<Size>>,
+    /// Space inside of the component in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub padding: Vec<Responsive<Size>>,
+    /// Space around the component in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub margin: Vec<Responsive<Size>>,
+    /// Min width in pixels of each screen for the responsive props.
+    /// Default `Breakpoints::default()`
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: Children,
+}
+
+impl Component for Grid {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        mount_styles(LAYOUTS_STYLES);
+
+        Grid { props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div class=classes!(
+                    "grid",
+                    self.props.class_name.clone(),
+                    StyleSource::from(GridModel.get_grid_css(self.props.clone())),
+                    self.props.styles.clone()
+                )
+                id=self.props.id.to_string()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+impl GridModel {
+    fn get_grid_css(self, props: Props) -> String {
+        format!(
+            "grid-auto-flow: {};\n{}{}{}{}{}{}{}{}",
+            self.get_auto_flow(props.auto_flow),
+            get_responsive_css(&props.breakpoints, props.columns, |template| format!(
+                "grid-template-columns: {};",
+                self.get_template(template)
+            )),
+            get_responsive_css(&props.breakpoints, props.rows, |template| format!(
+                "grid-template-rows: {};",
+                self.get_template(template)
+            )),
+            get_responsive_css(&props.breakpoints, props.areas, |areas| format!(
+                "grid-template-areas: {};",
+                self.get_areas(areas)
+            )),
+            get_spacing_css(&props.breakpoints, "gap", props.gap),
+            get_spacing_css(&props.breakpoints, "row-gap", props.row_gap),
+            get_spacing_css(&props.breakpoints, "column-gap", props.column_gap),
+            get_spacing_css(&props.breakpoints, "padding", props.padding),
+            get_spacing_css(&props.breakpoints, "margin", props.margin),
+        )
+    }
+
+    fn get_auto_flow(self, auto_flow: AutoFlow) -> String {
+        match auto_flow {
+            AutoFlow::Row => "row".to_string(),
+            AutoFlow::Column => "column".to_string(),
+            AutoFlow::RowDense => "row dense".to_string(),
+            AutoFlow::ColumnDense => "column dense".to_string(),
+        }
+    }
+
+    fn get_track(self, track: GridTrack) -> String {
+        match track {
+            GridTrack::Fr(value) => format!("{}fr", value),
+            GridTrack::Px(value) => format!("{}px", value),
+            GridTrack::Percent(value) => format!("{}%", value),
+            GridTrack::Auto => "auto".to_string(),
+            GridTrack::MinContent => "min-content".to_string(),
+            GridTrack::MaxContent => "max-content".to_string(),
+            GridTrack::MinMax(min, max) => {
+                format!("minmax({}, {})", self.get_track(*min), self.get_track(*max))
+            }
+        }
+    }
+
+    fn get_template(self, template: GridTemplate) -> String {
+        match template {
+            GridTemplate::None => "none".to_string(),
+            GridTemplate::Tracks(tracks) => tracks
+                .into_iter()
+                .map(|track| self.get_track(track))
+                .collect::<Vec<String>>()
+                .join(" "),
+            GridTemplate::Repeat(times, track) => {
+                format!("repeat({}, {})", times.max(1), self.get_track(track))
+            }
+            GridTemplate::AutoFill(track) => {
+                format!("repeat(auto-fill, {})", self.get_track(track))
+            }
+            GridTemplate::AutoFit(track) => format!("repeat(auto-fit, {})", self.get_track(track)),
+        }
+    }
+
+    fn get_areas(self, areas: GridAreas) -> String {
+        match areas {
+            GridAreas::None => "none".to_string(),
+            GridAreas::Rows(rows) => rows
+                .into_iter()
+                .map(|row| {
+                    let names = row
+                        .split_whitespace()
+                        .map(get_area_name)
+                        .filter(|name| !name.is_empty())
+                        .collect::<Vec<String>>()
+                        .join(" ");
+
+                    format!("\"{}\"", names)
+                })
+                .collect::<Vec<String>>()
+                .join(" "),
+        }
+    }
+}
+
+/// Removes the characters that are not valid in the name of an area, like `;`, `}` or `"`
+pub(crate) fn get_area_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_' || *c == '.')
+        .collect()
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_a_grid() {
+    let props_grid = Props {
+        columns: vec![Responsive::Xs(GridTemplate::Repeat(3, GridTrack::Fr(1.0)))],
+        rows: vec![],
+        areas: vec![],
+        auto_flow: AutoFlow::Row,
+        gap: vec![],
+        row_gap: vec![],
+        column_gap: vec![],
+        padding: vec![],
+        margin: vec![],
+        key: "".to_string(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        class_name: String::from("grid-test"),
+        styles: css!("color: red;"),
+        id: String::from("grid-id-test"),
+        children: Children::new(vec![html! {
+            <div id="grid">{"Grid"}</div>
+        }]),
+    };
+
+    let grid: App<Grid> = App::new();
+    grid.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props_grid,
+    );
+
+    let grid_element = utils::document().get_element_by_id("grid").unwrap();
+
+    assert_eq!(grid_element.text_content().unwrap(), "Grid".to_string());
+}
+
+#[wasm_bindgen_test]
+fn should_get_grid_css_from_props() {
+    let props_grid = Props {
+        columns: vec![
+            Responsive::M(GridTemplate::Tracks(vec![
+                GridTrack::Px(200.0),
+                GridTrack::MinMax(Box::new(GridTrack::Px(100.0)), Box::new(GridTrack::Fr(1.0))),
+            ])),
+            Responsive::Xs(GridTemplate::AutoFit(GridTrack::Percent(50.0))),
+        ],
+        rows: vec![],
+        areas: vec![Responsive::Xs(GridAreas::Rows(vec![
+            "header header".to_string(),
+            "aside main".to_string(),
+        ]))],
+        auto_flow: AutoFlow::ColumnDense,
+        gap: vec![],
+        row_gap: vec![],
+        column_gap: vec![],
+        padding: vec![],
+        margin: vec![],
+        key: "".to_string(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        class_name: String::from("grid-test"),
+        styles: css!("color: red;"),
+        id: String::from("grid-id-test"),
+        children: Children::new(vec![]),
+    };
+
+    assert_eq!(
+        GridModel.get_grid_css(props_grid),
+        "grid-auto-flow: column dense;
+grid-template-columns: repeat(auto-fit, 50%);
+@media (min-width: 768px) { grid-template-columns: 200px minmax(100px, 1fr); }
+grid-template-areas: \"header header\" \"aside main\";
+"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_grid_css_with_rows_and_spacing() {
+    let props_grid = Props {
+        columns: vec![],
+        rows: vec![
+            Responsive::Xs(GridTemplate::Repeat(0, GridTrack::Auto)),
+            Responsive::L(GridTemplate::None),
+        ],
+        areas: vec![],
+        auto_flow: AutoFlow::Row,
+        gap: vec![Responsive::Xs(Size::Small)],
+        row_gap: vec![],
+        column_gap: vec![],
+        padding: vec![Responsive::M(Size::Big)],
+        margin: vec![],
+        key: "".to_string(),
+        breakpoints: Breakpoints {
+            s: 480,
+            m: 720,
+            l: 1024,
+            xl: 1440,
+        },
+        code_ref: NodeRef::default(),
+        class_name: String::from("grid-test"),
+        styles: css!(""),
+        id: String::from("grid-id-test"),
+        children: Children::new(vec![]),
+    };
+
+    assert_eq!(
+        GridModel.get_grid_css(props_grid),
+        "grid-auto-flow: row;
+grid-template-rows: repeat(1, auto);
+@media (min-width: 1024px) { grid-template-rows: none; }
+gap: var(--ys-spacing-small, 8px);
+@media (min-width: 720px) { padding: var(--ys-spacing-big, 24px); }
+"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_remove_the_invalid_characters_of_the_areas() {
+    assert_eq!(
+        GridModel.get_areas(GridAreas::Rows(vec![
+            "header header".to_string(),
+            "aside main\"; } .grid { color: red;".to_string(),
+        ])),
+        "\"header header\" \"aside main .grid color red\""
+    );
+    assert_eq!(get_area_name("main;}"), "main".to_string());
+}
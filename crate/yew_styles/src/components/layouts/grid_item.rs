@@ -0,0 +1,308 @@
+use super::grid::get_area_name;
+use crate::embedded_styles::{mount_styles, LAYOUTS_STYLES};
+use crate::theme::{get_responsive_css, Breakpoints, Responsive};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// Lines of the grid where the item is placed. The line 0 does not exist
+/// and an item takes at least one track, so the zero values are applied as 1
+#[derive(Clone, PartialEq)]
+pub enum GridSpan {
+    Auto,
+    /// Number of tracks that will take the item from its automatic position
+    Span(u16),
+    /// Start line and end line
+    Lines(i16, i16),
+    /// Start line and number of tracks that will take the item
+    LineSpan(i16, u16),
+}
+
+/// # Grid Item component
+///
+/// ## Features required
+///
+/// layouts
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::{
+///     layouts::{
+///         grid::{Grid, GridAreas, GridTemplate, GridTrack},
+///         grid_item::GridItem,
+///     },
+///     theme::Responsive,
+/// };
+///
+/// pub struct App;
+///
+/// impl Component for App {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+///         App {}
+///     }
+///
+///     fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///        html! {
+///          <Grid
+///             columns=vec![Responsive::Xs(GridTemplate::Tracks(vec![GridTrack::Px(200.0), GridTrack::Fr(1.0)]))]
+///             areas=vec![Responsive::Xs(GridAreas::Rows(vec!["aside main".to_string()]))]
+///          >
+///                <GridItem area=vec![Responsive::Xs("aside".to_string())]>
+///                    <h3>{"menu"}</h3>
+///                </GridItem>
+///                <GridItem area=vec![Responsive::Xs("main".to_string())]>
+///                    <h3>{"content"}</h3>
+///                </GridItem>
+///           </Grid>
+///        }
+///     }
+/// }
+/// ```
+pub struct GridItem {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Clone, Copy)]
+struct GridItemModel;
+
+pub enum Msg {
+    Clicked(MouseEvent),
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Columns that will take the item in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub column: Vec<Responsive<GridSpan>>,
+    /// Rows that will take the item in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub row: Vec<Responsive<GridSpan>>,
+    /// Named area of the grid where is placed the item in each screen.
+    /// Only letters, numbers, `-`, `_` and `.` are kept in the name. Default `vec![]`
+    #[prop_or_default]
+    pub area: Vec<Responsive<String>>,
+    /// Min width in pixels of each screen for the responsive props.
+    /// Default `Breakpoints::default()`
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Click event for the item
+    #[prop_or(Callback::noop())]
+    pub onclick_signal: Callback<MouseEvent>,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: Children,
+}
+
+impl Component for GridItem {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(LAYOUTS_STYLES);
+
+        GridItem { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Clicked(mouse_event) => {
+                self.props.onclick_signal.emit(mouse_event);
+            }
+        };
+
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=classes!(
+                    "grid-item",
+                    self.props.class_name.clone(),
+                    StyleSource::from(GridItemModel.get_grid_item_css(self.props.clone())),
+                    self.props.styles.clone()
+                )
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onclick=self.link.callback(Msg::Clicked)
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+impl GridItemModel {
+    fn get_grid_item_css(self, props: Props) -> String {
+        format!(
+            "{}{}{}",
+            get_responsive_css(&props.breakpoints, props.column, |span| format!(
+                "grid-column: {};",
+                self.get_span(span)
+            )),
+            get_responsive_css(&props.breakpoints, props.row, |span| format!(
+                "grid-row: {};",
+                self.get_span(span)
+            )),
+            get_responsive_css(&props.breakpoints, props.area, |area| format!(
+                "grid-area: {};",
+                get_area_name(&area)
+            )),
+        )
+    }
+
+    fn get_span(self, span: GridSpan) -> String {
+        match span {
+            GridSpan::Auto => "auto".to_string(),
+            GridSpan::Span(tracks) => format!("span {}", tracks.max(1)),
+            GridSpan::Lines(start, end) => {
+                format!("{} / {}", self.get_line(start), self.get_line(end))
+            }
+            GridSpan::LineSpan(start, tracks) => {
+                format!("{} / span {}", self.get_line(start), tracks.max(1))
+            }
+        }
+    }
+
+    fn get_line(self, line: i16) -> i16 {
+        if line == 0 {
+            1
+        } else {
+            line
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_a_grid_item() {
+    let props_grid_item = Props {
+        column: vec![Responsive::Xs(GridSpan::Span(2))],
+        row: vec![],
+        area: vec![],
+        key: "".to_string(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        class_name: String::from("grid-item-test"),
+        styles: css!("color: red;"),
+        id: String::from("grid-item-id-test"),
+        onclick_signal: Callback::noop(),
+        children: Children::new(vec![html! {
+            <div id="grid-item">{"Grid item"}</div>
+        }]),
+    };
+
+    let grid_item: App<GridItem> = App::new();
+    grid_item.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props_grid_item,
+    );
+
+    let grid_item_element = utils::document().get_element_by_id("grid-item").unwrap();
+
+    assert_eq!(
+        grid_item_element.text_content().unwrap(),
+        "Grid item".to_string()
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_grid_item_css_from_props() {
+    let props_grid_item = Props {
+        column: vec![
+            Responsive::L(GridSpan::Lines(1, -1)),
+            Responsive::Xs(GridSpan::Auto),
+        ],
+        row: vec![Responsive::Xs(GridSpan::LineSpan(2, 3))],
+        area: vec![],
+        key: "".to_string(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        class_name: String::from("grid-item-test"),
+        styles: css!("color: red;"),
+        id: String::from("grid-item-id-test"),
+        onclick_signal: Callback::noop(),
+        children: Children::new(vec![]),
+    };
+
+    assert_eq!(
+        GridItemModel.get_grid_item_css(props_grid_item),
+        "grid-column: auto;
+@media (min-width: 992px) { grid-column: 1 / -1; }
+grid-row: 2 / span 3;
+"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_grid_item_css_without_zero_lines_and_spans() {
+    let props_grid_item = Props {
+        column: vec![
+            Responsive::Xs(GridSpan::Span(0)),
+            Responsive::M(GridSpan::Lines(0, -1)),
+        ],
+        row: vec![Responsive::Xs(GridSpan::LineSpan(0, 0))],
+        area: vec![Responsive::M("main".to_string())],
+        key: "".to_string(),
+        breakpoints: Breakpoints {
+            s: 480,
+            m: 720,
+            l: 1024,
+            xl: 1440,
+        },
+        code_ref: NodeRef::default(),
+        class_name: String::from("grid-item-test"),
+        styles: css!(""),
+        id: String::from("grid-item-id-test"),
+        onclick_signal: Callback::noop(),
+        children: Children::new(vec![]),
+    };
+
+    assert_eq!(
+        GridItemModel.get_grid_item_css(props_grid_item),
+        "grid-column: span 1;
+@media (min-width: 720px) { grid-column: 1 / -1; }
+grid-row: 1 / span 1;
+@media (min-width: 720px) { grid-area: main; }
+"
+    );
+}
@@ -1,4 +1,6 @@
-use crate::utils::{create_style, get_random_string};
+use crate::embedded_styles::{mount_styles, LAYOUTS_STYLES};
+use crate::styles::Size;
+use crate::theme::{get_responsive_css, get_spacing_css, Breakpoints, Responsive};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use web_sys::window;
@@ -6,6 +8,7 @@ use yew::prelude::*;
 use yew::{utils, App};
 
 /// Percent of the layout that will take the item.
+/// Each layout is applied from the breakpoint of its screen
 #[derive(Clone, PartialEq)]
 pub enum ItemLayout {
     ItXs(i8),
@@ -15,6 +18,15 @@ pub enum ItemLayout {
     ItXl(i8),
 }
 
+/// Initial size of the item before the free space is distributed
+#[derive(Clone, PartialEq)]
+pub enum FlexBasis {
+    Auto,
+    Content,
+    Percent(f64),
+    Px(f64),
+}
+
 /// Align the item itself
 #[derive(Clone, PartialEq)]
 pub enum AlignSelf {
@@ -95,12 +107,11 @@ pub enum Msg {
 pub struct Item {
     link: ComponentLink<Self>,
     props: Props,
-    pub key: String,
 }
 
 #[derive(Clone, PartialEq)]
 struct ItemProps {
-    layouts_classes: String,
+    item_styles: StyleSource<'static>,
     class_name: String,
     styles: StyleSource<'static>,
 }
@@ -115,6 +126,31 @@ pub struct Props {
     /// Align the item itself. Default `AlignSelf::Auto`
     #[prop_or(AlignSelf::Auto)]
     pub align_self: AlignSelf,
+    /// Order of the item in the container in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub order: Vec<Responsive<i16>>,
+    /// Number of columns, of the 12 of the layout, skipped before the item in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub offset: Vec<Responsive<i8>>,
+    /// How much the item grows with the free space in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub flex_grow: Vec<Responsive<f64>>,
+    /// How much the item shrinks when there is not enough space in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub flex_shrink: Vec<Responsive<f64>>,
+    /// Initial size of the item in each screen, it overrides the size of `layouts`. Default `vec![]`
+    #[prop_or_default]
+    pub flex_basis: Vec<Responsive<FlexBasis>>,
+    /// Space inside of the component in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub padding: Vec<Responsive<Size>>,
+    /// Space around the component in each screen. Default `vec![]`
+    #[prop_or_default]
+    pub margin: Vec<Responsive<Size>>,
+    /// Min width in pixels of each screen for the responsive props.
+    /// Default `Breakpoints::default()`
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -141,9 +177,9 @@ impl Component for Item {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        let key = get_random_string(10);
+        mount_styles(LAYOUTS_STYLES);
 
-        Item { link, props, key }
+        Item { link, props }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
@@ -156,12 +192,6 @@ impl Component for Item {
         false
     }
 
-    fn rendered(&mut self, first_render: bool) {
-        if first_render {
-            ItemModel.init(self.props.align_self.clone(), self.key.clone());
-        }
-    }
-
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
             self.props = props;
@@ -176,7 +206,7 @@ impl Component for Item {
 
         html! {
             <div
-                class=classes!(format!("item item-{}", self.key), item_props.layouts_classes, item_props.class_name, item_props.styles)
+                class=classes!("item", item_props.item_styles, item_props.class_name, item_props.styles)
                 key=self.props.key.clone()
                 ref=self.props.code_ref.clone()
                 onclick=self.link.callback(Msg::Clicked)
@@ -190,7 +220,7 @@ impl Component for Item {
 impl From<Props> for ItemProps {
     fn from(props: Props) -> Self {
         ItemProps {
-            layouts_classes: ItemModel.get_layout_classes(props.layouts),
+            item_styles: StyleSource::from(ItemModel.get_item_css(props.clone())),
             class_name: props.class_name,
             styles: props.styles,
         }
@@ -198,41 +228,71 @@ impl From<Props> for ItemProps {
 }
 
 impl ItemModel {
-    fn init(self, align_self: AlignSelf, key: String) {
-        self.get_item_align(align_self, key);
+    fn get_item_css(self, props: Props) -> String {
+        format!(
+            "align-self: {};\n{}{}{}{}{}{}{}{}",
+            self.get_item_align(props.align_self),
+            self.get_layouts_css(&props.breakpoints, props.layouts),
+            get_spacing_css(&props.breakpoints, "padding", props.padding),
+            get_spacing_css(&props.breakpoints, "margin", props.margin),
+            get_responsive_css(&props.breakpoints, props.order, |order| format!(
+                "order: {};",
+                order
+            )),
+            get_responsive_css(&props.breakpoints, props.offset, |offset| format!(
+                "margin-inline-start: {}%;",
+                f64::from(offset) * 100.0 / 12.0
+            )),
+            get_responsive_css(&props.breakpoints, props.flex_grow, |grow| format!(
+                "flex-grow: {};",
+                grow
+            )),
+            get_responsive_css(&props.breakpoints, props.flex_shrink, |shrink| format!(
+                "flex-shrink: {};",
+                shrink
+            )),
+            get_responsive_css(&props.breakpoints, props.flex_basis, |basis| format!(
+                "flex-basis: {};",
+                self.get_flex_basis(basis)
+            )),
+        )
     }
 
-    fn get_layout_classes(self, layouts_prop: Vec<ItemLayout>) -> String {
-        let mut layouts = layouts_prop
-            .into_iter()
-            .map(|layout| self.get_layout(layout))
-            .collect::<String>();
-
-        layouts.truncate(layouts.len() - 1);
-        layouts
+    fn get_flex_basis(self, flex_basis: FlexBasis) -> String {
+        match flex_basis {
+            FlexBasis::Auto => "auto".to_string(),
+            FlexBasis::Content => "content".to_string(),
+            FlexBasis::Percent(value) => format!("{}%", value),
+            FlexBasis::Px(value) => format!("{}px", value),
+        }
     }
 
-    fn get_layout(self, item_layout: ItemLayout) -> String {
-        match item_layout {
-            ItemLayout::ItXs(size) => format!("it-xs-{} ", size),
-            ItemLayout::ItS(size) => format!("it-s-{} ", size),
-            ItemLayout::ItM(size) => format!("it-m-{} ", size),
-            ItemLayout::ItL(size) => format!("it-l-{} ", size),
-            ItemLayout::ItXl(size) => format!("it-xl-{} ", size),
-        }
+    fn get_layouts_css(self, breakpoints: &Breakpoints, layouts_prop: Vec<ItemLayout>) -> String {
+        let layouts = layouts_prop
+            .into_iter()
+            .map(|layout| match layout {
+                ItemLayout::ItXs(size) => Responsive::Xs(size),
+                ItemLayout::ItS(size) => Responsive::S(size),
+                ItemLayout::ItM(size) => Responsive::M(size),
+                ItemLayout::ItL(size) => Responsive::L(size),
+                ItemLayout::ItXl(size) => Responsive::Xl(size),
+            })
+            .collect::<Vec<Responsive<i8>>>();
+
+        get_responsive_css(breakpoints, layouts, |size| {
+            format!("flex-basis: {}%;", f64::from(size) * 100.0 / 12.0)
+        })
     }
 
-    fn get_item_align(self, align: AlignSelf, key: String) {
-        let value = match align {
+    fn get_item_align(self, align: AlignSelf) -> String {
+        match align {
             AlignSelf::Auto => "auto".to_string(),
             AlignSelf::Baseline => "baseline".to_string(),
             AlignSelf::Center => "center".to_string(),
             AlignSelf::FlexStart => "flex-start".to_string(),
             AlignSelf::FlexEnd => "flex-end".to_string(),
             AlignSelf::Stretch => "stretch".to_string(),
-        };
-
-        create_style(String::from("align-self"), value, format!("item-{}", key));
+        }
     }
 }
 
@@ -243,7 +303,15 @@ fn should_create_item() {
     let props_item = Props {
         layouts: vec![ItemLayout::ItXs(12)],
         align_self: AlignSelf::Center,
+        padding: vec![],
+        margin: vec![],
+        order: vec![],
+        offset: vec![],
+        flex_grow: vec![],
+        flex_shrink: vec![],
+        flex_basis: vec![],
         key: "".to_string(),
+        breakpoints: Breakpoints::default(),
         code_ref: NodeRef::default(),
         class_name: "item-test".to_string(),
         id: "item-id-test".to_string(),
@@ -286,7 +354,15 @@ fn should_create_clickable_item() {
     let props_item = Props {
         layouts: vec![ItemLayout::ItXs(12)],
         align_self: AlignSelf::Center,
+        padding: vec![],
+        margin: vec![],
+        order: vec![],
+        offset: vec![],
+        flex_grow: vec![],
+        flex_shrink: vec![],
+        flex_basis: vec![],
         key: "".to_string(),
+        breakpoints: Breakpoints::default(),
         code_ref: NodeRef::default(),
         class_name: "item-test".to_string(),
         id: "item-id-test".to_string(),
@@ -312,3 +388,51 @@ fn should_create_clickable_item() {
 
     assert_eq!(updated_content, "item2".to_string());
 }
+
+#[wasm_bindgen_test]
+fn should_get_layouts_css_from_breakpoints() {
+    let layouts_css = ItemModel.get_layouts_css(
+        &Breakpoints::default(),
+        vec![ItemLayout::ItM(6), ItemLayout::ItXs(12)],
+    );
+
+    assert_eq!(
+        layouts_css,
+        "flex-basis: 100%;\n@media (min-width: 768px) { flex-basis: 50%; }\n"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_item_css_with_order_and_offset() {
+    let props_item = Props {
+        layouts: vec![ItemLayout::ItXs(6)],
+        align_self: AlignSelf::Auto,
+        padding: vec![],
+        margin: vec![],
+        order: vec![Responsive::Xs(2), Responsive::M(0)],
+        offset: vec![Responsive::M(3)],
+        flex_grow: vec![Responsive::Xs(1.0)],
+        flex_shrink: vec![],
+        flex_basis: vec![Responsive::L(FlexBasis::Px(240.0))],
+        key: "".to_string(),
+        breakpoints: Breakpoints::default(),
+        code_ref: NodeRef::default(),
+        class_name: "item-test".to_string(),
+        id: "item-id-test".to_string(),
+        onclick_signal: Callback::noop(),
+        styles: css!(""),
+        children: Children::new(vec![]),
+    };
+
+    assert_eq!(
+        ItemModel.get_item_css(props_item),
+        "align-self: auto;
+flex-basis: 50%;
+order: 2;
+@media (min-width: 768px) { order: 0; }
+@media (min-width: 768px) { margin-inline-start: 25%; }
+flex-grow: 1;
+@media (min-width: 992px) { flex-basis: 240px; }
+"
+    );
+}
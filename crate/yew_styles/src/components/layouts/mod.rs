@@ -1,2 +1,5 @@
 pub mod container;
+pub mod grid;
+pub mod grid_item;
 pub mod item;
+pub mod split_pane;
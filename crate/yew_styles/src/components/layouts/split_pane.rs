@@ -0,0 +1,605 @@
+use crate::embedded_styles::{mount_styles, LAYOUTS_STYLES};
+use crate::styles::{Palette, Style};
+use crate::theme::{get_theme_color, ThemeColor};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{window, Element, HtmlElement, Storage};
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// Percent moved by the arrow keys when the divider is focused
+const KEYBOARD_STEP: f64 = 5.0;
+
+/// Where are placed the panes
+#[derive(Clone, PartialEq)]
+pub enum SplitDirection {
+    /// Panes in a row, separated by vertical dividers
+    Horizontal,
+    /// Panes in a column, separated by horizontal dividers
+    Vertical,
+}
+
+/// Size limits of a pane in percent of the split pane
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaneOptions {
+    pub size: f64,
+    pub min_size: f64,
+    pub max_size: f64,
+    /// The pane collapses to the edge when it is dragged below half of its min size
+    pub collapsible: bool,
+}
+
+impl PaneOptions {
+    pub fn new(size: f64) -> Self {
+        PaneOptions {
+            size,
+            min_size: 0.0,
+            max_size: 100.0,
+            collapsible: false,
+        }
+    }
+}
+
+/// # Split Pane component
+///
+/// Each child is a pane, the dividers between them can be dragged
+/// with the pointer or moved with the arrow keys to resize the panes.
+/// The divider captures the pointer while it is dragged, so the resize continues outside of the panes.
+/// In right to left direction the horizontal movements are mirrored
+///
+/// ## Features required
+///
+/// layouts
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::layouts::split_pane::{PaneOptions, SplitDirection, SplitPane};
+///
+/// pub struct App {
+///   link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///   Resized(Vec<f64>),
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         false
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///        html! {
+///          <SplitPane
+///             direction=SplitDirection::Horizontal
+///             panes=vec![
+///                 PaneOptions { min_size: 15.0, collapsible: true, ..PaneOptions::new(25.0) },
+///                 PaneOptions::new(75.0),
+///             ]
+///             storage_key=Some("editor-panes".to_string())
+///             onresize_signal=self.link.callback(Msg::Resized)
+///          >
+///             <div>{"files"}</div>
+///             <div>{"editor"}</div>
+///          </SplitPane>
+///        }
+///     }
+/// }
+/// ```
+pub struct SplitPane {
+    link: ComponentLink<Self>,
+    props: Props,
+    sizes: Vec<f64>,
+    dragging: Option<Dragging>,
+}
+
+struct Dragging {
+    divider: usize,
+    start_position: i32,
+    start_sizes: Vec<f64>,
+}
+
+pub enum Msg {
+    StartResize(usize, PointerEvent),
+    Resize(PointerEvent),
+    EndResize(PointerEvent),
+    KeyResize(usize, KeyboardEvent),
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Where are placed the panes. Default `SplitDirection::Horizontal`
+    #[prop_or(SplitDirection::Horizontal)]
+    pub direction: SplitDirection,
+    /// Size limits of each pane. Without them the panes take the same size. Default `vec![]`
+    #[prop_or_default]
+    pub panes: Vec<PaneOptions>,
+    /// Key of the localStorage where the sizes are saved after each resize. Default `None`
+    #[prop_or_default]
+    pub storage_key: Option<String>,
+    /// Signal with the new sizes in percent after each resize
+    #[prop_or(Callback::noop())]
+    pub onresize_signal: Callback<Vec<f64>>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: Children,
+}
+
+impl Component for SplitPane {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(LAYOUTS_STYLES);
+
+        let sizes = get_initial_sizes(&props);
+
+        SplitPane {
+            link,
+            props,
+            sizes,
+            dragging: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::StartResize(divider, pointer_event) => {
+                pointer_event.prevent_default();
+
+                if let Some(divider_element) = get_event_element(&pointer_event) {
+                    divider_element
+                        .set_pointer_capture(pointer_event.pointer_id())
+                        .ok();
+                }
+
+                self.dragging = Some(Dragging {
+                    divider,
+                    start_position: self.get_position(&pointer_event),
+                    start_sizes: self.sizes.clone(),
+                });
+            }
+            Msg::Resize(pointer_event) => {
+                if let Some(dragging) = &self.dragging {
+                    let split_pane_size = self.get_split_pane_size();
+
+                    if split_pane_size > 0.0 {
+                        let moved = self.get_position(&pointer_event) - dragging.start_position;
+                        let delta =
+                            f64::from(moved) * 100.0 / split_pane_size * self.get_direction_sign();
+
+                        self.sizes = resize_panes(
+                            &dragging.start_sizes,
+                            &self.props.panes,
+                            dragging.divider,
+                            delta,
+                        );
+                    }
+                } else {
+                    return false;
+                }
+            }
+            Msg::EndResize(pointer_event) => {
+                if let Some(divider_element) = get_event_element(&pointer_event) {
+                    divider_element
+                        .release_pointer_capture(pointer_event.pointer_id())
+                        .ok();
+                }
+
+                if self.dragging.take().is_none() {
+                    return false;
+                }
+
+                self.resized();
+            }
+            Msg::KeyResize(divider, keyboard_event) => {
+                let delta = match keyboard_event.key().as_str() {
+                    "ArrowUp" => -KEYBOARD_STEP,
+                    "ArrowDown" => KEYBOARD_STEP,
+                    "ArrowLeft" => -KEYBOARD_STEP * self.get_direction_sign(),
+                    "ArrowRight" => KEYBOARD_STEP * self.get_direction_sign(),
+                    _ => return false,
+                };
+
+                keyboard_event.prevent_default();
+                self.sizes = resize_panes(&self.sizes, &self.props.panes, divider, delta);
+                self.resized();
+            }
+        };
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            let reset_sizes = self.props.panes != props.panes
+                || self.props.children.len() != props.children.len();
+
+            self.props = props;
+
+            if reset_sizes {
+                self.sizes = get_initial_sizes(&self.props);
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let panes_number = self.props.children.len();
+
+        html! {
+            <div
+                class=classes!(
+                    "split-pane",
+                    get_split_direction(self.props.direction.clone()),
+                    if self.dragging.is_some() { "dragging" } else { "" },
+                    StyleSource::from(get_divider_css()),
+                    self.props.class_name.clone(),
+                    self.props.styles.clone()
+                )
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.children.iter().enumerate().map(|(index, pane)| {
+                    html! {
+                        <>
+                            <div
+                                class=classes!("split-pane-pane", if self.sizes.get(index) == Some(&0.0) { "collapsed" } else { "" })
+                                style=format!("flex-basis: {}%;", self.sizes.get(index).cloned().unwrap_or(0.0))
+                            >
+                                {pane}
+                            </div>
+                            {if index + 1 < panes_number {
+                                self.get_divider(index)
+                            } else {
+                                html! {}
+                            }}
+                        </>
+                    }
+                }).collect::<Html>()}
+            </div>
+        }
+    }
+}
+
+impl SplitPane {
+    fn get_divider(&self, index: usize) -> Html {
+        let pane = get_pane_options(&self.props.panes, index);
+
+        html! {
+            <div
+                class="split-pane-divider"
+                role="separator"
+                tabindex="0"
+                aria-orientation={if self.props.direction == SplitDirection::Horizontal { "vertical" } else { "horizontal" }}
+                aria-valuenow=self.sizes.get(index).cloned().unwrap_or(0.0).round().to_string()
+                aria-valuemin=pane.min_size.to_string()
+                aria-valuemax=pane.max_size.min(100.0).to_string()
+                onpointerdown=self.link.callback(move |pointer_event| Msg::StartResize(index, pointer_event))
+                onpointermove=self.link.callback(Msg::Resize)
+                onpointerup=self.link.callback(Msg::EndResize)
+                onpointercancel=self.link.callback(Msg::EndResize)
+                onkeydown=self.link.callback(move |keyboard_event| Msg::KeyResize(index, keyboard_event))
+            />
+        }
+    }
+
+    fn get_position(&self, mouse_event: &MouseEvent) -> i32 {
+        match self.props.direction {
+            SplitDirection::Horizontal => mouse_event.client_x(),
+            SplitDirection::Vertical => mouse_event.client_y(),
+        }
+    }
+
+    /// `-1.0` when the panes are placed in a row from right to left, otherwise `1.0`
+    fn get_direction_sign(&self) -> f64 {
+        if self.props.direction == SplitDirection::Vertical {
+            return 1.0;
+        }
+
+        let rtl = self
+            .props
+            .code_ref
+            .cast::<HtmlElement>()
+            .and_then(|split_pane| window()?.get_computed_style(&split_pane).ok().flatten())
+            .and_then(|style| style.get_property_value("direction").ok())
+            .map_or(false, |direction| direction == "rtl");
+
+        if rtl {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    fn get_split_pane_size(&self) -> f64 {
+        match self.props.code_ref.cast::<HtmlElement>() {
+            Some(split_pane) => match self.props.direction {
+                SplitDirection::Horizontal => f64::from(split_pane.offset_width()),
+                SplitDirection::Vertical => f64::from(split_pane.offset_height()),
+            },
+            None => 0.0,
+        }
+    }
+
+    fn resized(&self) {
+        if let Some(storage_key) = &self.props.storage_key {
+            store_sizes(storage_key, &self.sizes);
+        }
+
+        self.props.onresize_signal.emit(self.sizes.clone());
+    }
+}
+
+fn get_event_element(event: &PointerEvent) -> Option<Element> {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+}
+
+fn get_split_direction(direction: SplitDirection) -> String {
+    match direction {
+        SplitDirection::Horizontal => "horizontal".to_string(),
+        SplitDirection::Vertical => "vertical".to_string(),
+    }
+}
+
+fn get_divider_css() -> String {
+    format!(
+        ".split-pane-divider {{
+            background: {background};
+        }}
+        .split-pane-divider:hover, .split-pane-divider:focus {{
+            background: {hover};
+        }}",
+        background = get_theme_color(Palette::Standard, Style::Light, ThemeColor::Background),
+        hover = get_theme_color(Palette::Standard, Style::Light, ThemeColor::BackgroundHover),
+    )
+}
+
+fn get_pane_options(panes: &[PaneOptions], index: usize) -> PaneOptions {
+    panes
+        .get(index)
+        .cloned()
+        .unwrap_or_else(|| PaneOptions::new(0.0))
+}
+
+fn get_initial_sizes(props: &Props) -> Vec<f64> {
+    let panes_number = props.children.len();
+
+    if let Some(stored_sizes) = props
+        .storage_key
+        .as_deref()
+        .and_then(get_stored_sizes)
+        .and_then(|stored_sizes| get_valid_sizes(stored_sizes, &props.panes, panes_number))
+    {
+        return stored_sizes;
+    }
+
+    if props.panes.len() == panes_number {
+        props.panes.iter().map(|pane| pane.size).collect()
+    } else {
+        vec![100.0 / panes_number as f64; panes_number]
+    }
+}
+
+/// Size of a pane inside of its limits, below half of the min size a collapsible pane collapses
+fn limit_pane_size(size: f64, pane: &PaneOptions) -> f64 {
+    if size < pane.min_size {
+        if pane.collapsible && size < pane.min_size / 2.0 {
+            0.0
+        } else {
+            pane.min_size
+        }
+    } else {
+        size.min(pane.max_size)
+    }
+}
+
+/// Sizes inside of the limits of each pane, `None` if they are not numbers,
+/// there is not one for each pane or they don't fill the split pane
+fn get_valid_sizes(
+    sizes: Vec<f64>,
+    panes: &[PaneOptions],
+    panes_number: usize,
+) -> Option<Vec<f64>> {
+    if sizes.len() != panes_number || sizes.iter().any(|size| !size.is_finite()) {
+        return None;
+    }
+
+    let sizes = sizes
+        .into_iter()
+        .enumerate()
+        .map(|(index, size)| limit_pane_size(size, &get_pane_options(panes, index)))
+        .collect::<Vec<f64>>();
+
+    if (sizes.iter().sum::<f64>() - 100.0).abs() < 0.001 {
+        Some(sizes)
+    } else {
+        None
+    }
+}
+
+/// Move the divider between the pane `divider` and the next one,
+/// the space taken by both panes doesn't change and the limits of the next pane win
+fn resize_panes(sizes: &[f64], panes: &[PaneOptions], divider: usize, delta: f64) -> Vec<f64> {
+    let mut new_sizes = sizes.to_vec();
+
+    if divider + 1 >= sizes.len() {
+        return new_sizes;
+    }
+
+    let before_pane = get_pane_options(panes, divider);
+    let after_pane = get_pane_options(panes, divider + 1);
+    let total = sizes[divider] + sizes[divider + 1];
+
+    let before_size = limit_pane_size(sizes[divider] + delta, &before_pane);
+    let after_size = limit_pane_size(total - before_size, &after_pane);
+    let before_size = limit_pane_size(total - after_size, &before_pane);
+
+    if (before_size + after_size - total).abs() < 0.001 {
+        new_sizes[divider] = before_size;
+        new_sizes[divider + 1] = total - before_size;
+    }
+
+    new_sizes
+}
+
+fn get_local_storage() -> Option<Storage> {
+    window()?.local_storage().ok().flatten()
+}
+
+fn get_stored_sizes(storage_key: &str) -> Option<Vec<f64>> {
+    let stored_sizes = get_local_storage()?.get_item(storage_key).ok().flatten()?;
+
+    stored_sizes
+        .split(',')
+        .map(|size| size.parse::<f64>().ok())
+        .collect()
+}
+
+fn store_sizes(storage_key: &str, sizes: &[f64]) {
+    if let Some(storage) = get_local_storage() {
+        let sizes = sizes
+            .iter()
+            .map(|size| size.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        storage.set_item(storage_key, &sizes).ok();
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_split_pane() {
+    let props_split_pane = Props {
+        direction: SplitDirection::Horizontal,
+        panes: vec![],
+        storage_key: None,
+        onresize_signal: Callback::noop(),
+        key: "".to_string(),
+        code_ref: NodeRef::default(),
+        class_name: String::from("split-pane-test"),
+        styles: css!("height: 200px;"),
+        id: String::from("split-pane-id-test"),
+        children: Children::new(vec![
+            html! {<div id="first-pane">{"first"}</div>},
+            html! {<div id="second-pane">{"second"}</div>},
+        ]),
+    };
+
+    let split_pane: App<SplitPane> = App::new();
+    split_pane.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props_split_pane,
+    );
+
+    let dividers = utils::document().get_elements_by_class_name("split-pane-divider");
+    let second_pane = utils::document().get_element_by_id("second-pane").unwrap();
+
+    assert_eq!(dividers.length(), 1);
+    assert_eq!(second_pane.text_content().unwrap(), "second".to_string());
+}
+
+#[wasm_bindgen_test]
+fn should_resize_panes_inside_of_limits() {
+    let panes = vec![
+        PaneOptions {
+            min_size: 20.0,
+            collapsible: true,
+            ..PaneOptions::new(30.0)
+        },
+        PaneOptions {
+            max_size: 75.0,
+            ..PaneOptions::new(70.0)
+        },
+    ];
+
+    assert_eq!(
+        resize_panes(&[30.0, 70.0], &panes, 0, 10.0),
+        vec![40.0, 60.0]
+    );
+    assert_eq!(
+        resize_panes(&[30.0, 70.0], &panes, 0, -15.0),
+        vec![25.0, 75.0]
+    );
+    assert_eq!(
+        resize_panes(&[30.0, 70.0], &panes, 0, -25.0),
+        vec![25.0, 75.0]
+    );
+    assert_eq!(resize_panes(&[30.0, 70.0], &[], 0, -25.0), vec![5.0, 95.0]);
+}
+
+#[wasm_bindgen_test]
+fn should_collapse_pane_to_the_edge() {
+    let panes = vec![
+        PaneOptions {
+            min_size: 20.0,
+            collapsible: true,
+            ..PaneOptions::new(30.0)
+        },
+        PaneOptions::new(70.0),
+    ];
+
+    assert_eq!(
+        resize_panes(&[30.0, 70.0], &panes, 0, -12.0),
+        vec![20.0, 80.0]
+    );
+    assert_eq!(
+        resize_panes(&[30.0, 70.0], &panes, 0, -25.0),
+        vec![0.0, 100.0]
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_reject_invalid_stored_sizes() {
+    let panes = vec![
+        PaneOptions {
+            min_size: 20.0,
+            ..PaneOptions::new(30.0)
+        },
+        PaneOptions::new(70.0),
+    ];
+
+    assert_eq!(
+        get_valid_sizes(vec![40.0, 60.0], &panes, 2),
+        Some(vec![40.0, 60.0])
+    );
+    assert_eq!(get_valid_sizes(vec![10.0, 90.0], &panes, 2), None);
+    assert_eq!(get_valid_sizes(vec![f64::NAN, 60.0], &panes, 2), None);
+    assert_eq!(get_valid_sizes(vec![50.0, 60.0], &panes, 2), None);
+    assert_eq!(get_valid_sizes(vec![100.0], &panes, 2), None);
+}
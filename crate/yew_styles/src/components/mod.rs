@@ -1,6 +1,9 @@
-extern crate getrandom;
+#[cfg(feature = "app_shell")]
+pub mod app_shell;
 #[cfg(feature = "button")]
 pub mod button;
+#[cfg(feature = "button_group")]
+pub mod button_group;
 #[cfg(feature = "card")]
 pub mod card;
 #[cfg(feature = "carousel")]
@@ -17,6 +20,8 @@ pub mod modal;
 pub mod navbar;
 #[cfg(feature = "spinner")]
 pub mod spinner;
+#[cfg(feature = "split_button")]
+pub mod split_button;
 #[cfg(feature = "text")]
 pub mod text;
 #[cfg(feature = "tooltip")]
@@ -1,9 +1,11 @@
+use crate::embedded_styles::{mount_styles, MODAL_STYLES};
 use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
-use crate::utils::get_html_element_by_class;
+use crate::theme::{get_palette_css, get_theme_color, ThemeColor};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
-use web_sys::Element;
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
 use yew::{utils, App};
 
@@ -133,7 +135,7 @@ pub struct Props {
     /// Type modal background style. Default `Palette::Standard`
     #[prop_or(Palette::Standard)]
     pub modal_palette: Palette,
-    /// Three diffent modal standard sizes. Default `Size::Medium`
+    /// Five diffent modal standard sizes or a custom scale. Default `Size::Medium`
     #[prop_or(Size::Medium)]
     pub modal_size: Size,
     /// Type modal header style. Default `Palette::Standard`
@@ -184,6 +186,8 @@ impl Component for Modal {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(MODAL_STYLES);
+
         Self { link, props }
     }
 
@@ -219,9 +223,9 @@ impl Component for Modal {
 
     fn rendered(&mut self, _first_render: bool) {
         if self.props.is_open && self.props.auto_focus {
-            let modal_form = get_html_element_by_class("modal", 0);
-
-            modal_form.focus().unwrap();
+            if let Some(modal_form) = self.props.code_ref.cast::<HtmlElement>() {
+                modal_form.focus().unwrap();
+            }
         }
     }
 
@@ -234,7 +238,17 @@ fn get_modal(props: Props, link: ComponentLink<Modal>) -> Html {
     if props.is_open {
         html! {
             <div
-                class=classes!("modal", "container", get_palette(props.modal_palette), props.class_name, props.styles)
+                class=classes!(
+                    "modal",
+                    "container",
+                    get_palette(props.modal_palette.clone()),
+                    StyleSource::from(format!(
+                        "background-color: {};",
+                        get_theme_color(props.modal_palette, Style::Regular, ThemeColor::Overlay)
+                    )),
+                    props.class_name,
+                    props.styles
+                )
                 key=props.key
                 ref=props.code_ref
                 tabindex="0"
@@ -242,20 +256,36 @@ fn get_modal(props: Props, link: ComponentLink<Modal>) -> Html {
                 onclick=link.callback(Msg::Clicked)
                 onkeydown=link.callback(Msg::Pressed)
             >
-                <div class=format!("modal-content {}", get_size(props.modal_size))>
-                    <div class=format!(
-                        "modal-header {} {} {}",
-                        get_style(props.header_style),
-                        get_palette(props.header_palette),
-                        if props.header_interaction { "interaction" } else { "" }
+                <div class=classes!(
+                    "modal-content",
+                    get_size(props.modal_size.clone()),
+                    StyleSource::from(get_custom_size_css(props.modal_size, |scale| {
+                        format!("&.modal-content.custom {{ width: calc(50% * {}); }}", scale)
+                    })),
+                )>
+                    <div class=classes!(
+                        "modal-header",
+                        get_style(props.header_style.clone()),
+                        get_palette(props.header_palette.clone()),
+                        if props.header_interaction { "interaction" } else { "" },
+                        StyleSource::from(get_palette_css(
+                            props.header_palette,
+                            props.header_style,
+                            props.header_interaction
+                        )),
                     )>
                         {props.header}
                     </div>
-                    <div class=format!(
-                        "modal-body {} {} {}",
-                        get_style(props.body_style),
-                        get_palette(props.body_palette),
-                        if props.body_interaction { "interaction" } else { "" }
+                    <div class=classes!(
+                        "modal-body",
+                        get_style(props.body_style.clone()),
+                        get_palette(props.body_palette.clone()),
+                        if props.body_interaction { "interaction" } else { "" },
+                        StyleSource::from(get_palette_css(
+                            props.body_palette,
+                            props.body_style,
+                            props.body_interaction
+                        )),
                     )>
                         {props.body}
                     </div>
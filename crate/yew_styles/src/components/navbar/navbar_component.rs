@@ -1,8 +1,9 @@
 use super::navbar_container::NavbarContainer;
 use super::navbar_item::NavbarItem;
+use crate::embedded_styles::{mount_styles, NAVBAR_STYLES};
 use crate::layouts::container::{Direction, JustifyContent, Mode};
 use crate::styles::{get_palette, get_style, Palette, Style};
-use crate::utils::create_style;
+use crate::theme::{get_theme_color, Breakpoints, ThemeColor};
 use stylist::{css, StyleSource};
 use yew::prelude::*;
 use yew::Children;
@@ -10,6 +11,8 @@ use yew_assets::ux_assets::{UxAssets, UxIcon};
 
 /// # Navbar component
 ///
+/// The navbar collapses in a menu in the screens smaller than the `l` breakpoint of `breakpoints`
+///
 /// ## Features required
 ///
 /// navbar
@@ -132,8 +135,6 @@ pub struct Navbar {
     pub display_menu: bool,
 }
 
-struct NavbarModel;
-
 #[derive(Clone, Properties, PartialEq)]
 pub struct Props {
     /// Type navbar style. Default `Standard`
@@ -145,6 +146,10 @@ pub struct Props {
     /// Navbar styles. Default Regular
     #[prop_or(Style::Regular)]
     pub navbar_style: Style,
+    /// Min width in pixels of each screen, the navbar is collapsed in screens smaller than `l`.
+    /// Default `Breakpoints::default()`
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
     /// General property to get the ref of the component
     #[prop_or_default]
     pub code_ref: NodeRef,
@@ -172,6 +177,10 @@ pub struct Props {
 pub struct NavbarProps {
     pub navbar_palette: String,
     pub navbar_style: String,
+    pub theme_styles: StyleSource<'static>,
+    pub mobile_styles: StyleSource<'static>,
+    pub desktop_styles: StyleSource<'static>,
+    pub fixed_styles: StyleSource<'static>,
     pub hide_navbar_items_mobile: bool,
     pub key: String,
     pub code_ref: NodeRef,
@@ -186,6 +195,13 @@ pub struct NavbarProps {
 impl From<Props> for NavbarProps {
     fn from(props: Props) -> Self {
         NavbarProps {
+            theme_styles: StyleSource::from(get_navbar_css(
+                props.navbar_palette.clone(),
+                props.navbar_style.clone(),
+            )),
+            mobile_styles: StyleSource::from(get_navbar_mobile_css(&props.breakpoints)),
+            desktop_styles: StyleSource::from(get_navbar_desktop_css(&props.breakpoints)),
+            fixed_styles: StyleSource::from(get_navbar_fixed_css(props.fixed.clone())),
             navbar_palette: get_palette(props.navbar_palette),
             navbar_style: get_style(props.navbar_style),
             hide_navbar_items_mobile: props.hide_navbar_items_mobile,
@@ -218,6 +234,8 @@ impl Component for Navbar {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(NAVBAR_STYLES);
+
         Navbar {
             link,
             props: NavbarProps::from(props),
@@ -235,15 +253,8 @@ impl Component for Navbar {
         true
     }
 
-    fn rendered(&mut self, first_render: bool) {
-        if first_render {
-            NavbarModel.init(self.props.clone());
-        }
-    }
-
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != NavbarProps::from(props.clone()) {
-            NavbarModel.init(self.props.clone());
             self.props = NavbarProps::from(props);
             if self.props.hide_navbar_items_mobile && self.display_menu {
                 self.display_menu = false;
@@ -258,7 +269,16 @@ impl Component for Navbar {
         html! {
             <>
                 <div
-                    class=classes!("navbar-mobile", self.props.navbar_style.clone(), self.props.navbar_palette.clone(), self.props.class_name.clone(), self.props.styles.clone())
+                    class=classes!(
+                        "navbar-mobile",
+                        self.props.navbar_style.clone(),
+                        self.props.navbar_palette.clone(),
+                        self.props.theme_styles.clone(),
+                        self.props.mobile_styles.clone(),
+                        self.props.fixed_styles.clone(),
+                        self.props.class_name.clone(),
+                        self.props.styles.clone()
+                    )
                     id=self.props.id.clone()
                     key=self.props.key.clone()
                     ref=self.props.code_ref.clone()
@@ -292,7 +312,15 @@ impl Component for Navbar {
                 </div>
 
                 <div
-                    class=format!("navbar {} {} {}", self.props.navbar_style, self.props.navbar_palette, self.props.class_name)
+                    class=classes!(
+                        "navbar",
+                        self.props.navbar_style.clone(),
+                        self.props.navbar_palette.clone(),
+                        self.props.theme_styles.clone(),
+                        self.props.desktop_styles.clone(),
+                        self.props.fixed_styles.clone(),
+                        self.props.class_name.clone()
+                    )
                 >
                 <NavbarContainer justify_content=JustifyContent::Start(Mode::NoMode)
                     direction=Direction::Row
@@ -310,34 +338,61 @@ impl Component for Navbar {
     }
 }
 
-impl NavbarModel {
-    fn init(self, props: NavbarProps) {
-        self.set_fixed(props.fixed);
+fn get_navbar_css(palette: Palette, style: Style) -> String {
+    format!(
+        "background: {background};
+        color: {color};
+        border: {border};
+        .navbar-menu {{
+            fill: {color};
+        }}
+        ul {{
+            background: {background};
+        }}
+        .navbar-item.interaction:focus, .navbar-dropdown:focus, .navbar-dropdown-item:focus {{
+            background: {focus};
+        }}
+        .navbar-item.interaction:hover, .navbar-dropdown:hover, .navbar-dropdown-item:hover {{
+            background: {hover};
+        }}
+        .navbar-item.interaction:active, .navbar-item.interaction.active,
+        .navbar-dropdown:active, .navbar-dropdown.active,
+        .navbar-dropdown-item:active, .navbar-dropdown-item.active {{
+            background: {active};
+        }}",
+        background = get_theme_color(palette.clone(), style.clone(), ThemeColor::Background),
+        color = get_theme_color(palette.clone(), style.clone(), ThemeColor::Color),
+        border = get_theme_color(palette.clone(), style.clone(), ThemeColor::Border),
+        focus = get_theme_color(palette.clone(), style.clone(), ThemeColor::BackgroundFocus),
+        hover = get_theme_color(palette.clone(), style.clone(), ThemeColor::BackgroundHover),
+        active = get_theme_color(palette, style, ThemeColor::BackgroundActive),
+    )
+}
+
+fn get_navbar_fixed_css(fixed: Fixed) -> String {
+    match fixed {
+        Fixed::None => String::new(),
+        Fixed::Top => "position: fixed;\ntop: 0;".to_string(),
+        Fixed::Bottom => "position: fixed;\nbottom: 0;".to_string(),
     }
+}
 
-    fn set_fixed(self, fixed: Fixed) {
-        create_style(
-            String::from("position"),
-            if fixed == Fixed::None {
-                String::from("inherit")
-            } else {
-                String::from("fixed")
-            },
-            String::from("navbar"),
-        );
+fn get_navbar_mobile_css(breakpoints: &Breakpoints) -> String {
+    format!(
+        "@media all and (min-width: {}px) {{
+            display: none !important;
+        }}",
+        breakpoints.l
+    )
+}
 
-        if fixed != Fixed::None {
-            create_style(
-                if fixed == Fixed::Top {
-                    String::from("top")
-                } else {
-                    String::from("bottom")
-                },
-                String::from("0"),
-                String::from("navbar"),
-            );
-        }
-    }
+fn get_navbar_desktop_css(breakpoints: &Breakpoints) -> String {
+    format!(
+        "@media all and (max-width: {}px) {{
+            display: none !important;
+        }}",
+        breakpoints.l.saturating_sub(1)
+    )
 }
 
 fn get_branch(branch: Html) -> Html {
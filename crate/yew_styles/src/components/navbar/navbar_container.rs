@@ -1,3 +1,4 @@
+use crate::embedded_styles::{mount_styles, NAVBAR_STYLES};
 use crate::layouts::container::{Container, Direction, JustifyContent, Mode, Wrap};
 use yew::prelude::*;
 
@@ -127,6 +128,8 @@ impl Component for NavbarContainer {
     type Properties = Props;
 
     fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        mount_styles(NAVBAR_STYLES);
+
         NavbarContainer { props }
     }
 
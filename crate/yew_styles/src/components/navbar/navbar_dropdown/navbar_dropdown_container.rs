@@ -1,4 +1,4 @@
-use crate::utils::{get_html_element_by_class, get_random_string};
+use crate::embedded_styles::{mount_styles, NAVBAR_STYLES};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -110,7 +110,6 @@ use yew::{utils, App};
 pub struct NavbarDropdown {
     props: Props,
     show: bool,
-    key: String,
     link: ComponentLink<Self>,
 }
 
@@ -146,12 +145,11 @@ impl Component for NavbarDropdown {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        let key = get_random_string(10);
+        mount_styles(NAVBAR_STYLES);
 
         Self {
             props,
             link,
-            key,
             show: false,
         }
     }
@@ -176,26 +174,10 @@ impl Component for NavbarDropdown {
         false
     }
 
-    fn rendered(&mut self, first_render: bool) {
-        if first_render {
-            let navbar_dropdown =
-                get_html_element_by_class(&format!("navbar-dropdown-{}", self.key), 0);
-            let navbar_dropdown_width = navbar_dropdown.offset_width();
-
-            let navbar_dropdown_container =
-                get_html_element_by_class(&format!("navbar-dropdown-container-{}", self.key), 0);
-
-            navbar_dropdown_container
-                .style()
-                .set_property("width", &format!("{}px", navbar_dropdown_width))
-                .unwrap()
-        }
-    }
-
     fn view(&self) -> Html {
         html! {
             <div
-                class=classes!("navbar-dropdown", format!("navbar-dropdown-{}", self.key), if self.props.active {
+                class=classes!("navbar-dropdown", if self.props.active {
                     "active"
                 } else {
                     ""
@@ -207,15 +189,15 @@ impl Component for NavbarDropdown {
                 onclick=self.link.callback(|_| Msg::HideDropdown)
                 >
                 <div class="main-content">{self.props.main_content.clone()}</div>
-                {get_items(self.show, self.key.clone(), self.props.children.clone())}
+                {get_items(self.show, self.props.children.clone())}
             </div>
         }
     }
 }
 
-fn get_items(show: bool, key: String, children: Children) -> Html {
+fn get_items(show: bool, children: Children) -> Html {
     html! {
-        <ul class=classes!(format!("navbar-dropdown-container-{}", key), if show { "active"} else {"inactive"})>
+        <ul class=classes!(if show { "active"} else {"inactive"})>
             {children.clone()}
         </ul>
     }
@@ -1,3 +1,4 @@
+use crate::embedded_styles::{mount_styles, NAVBAR_STYLES};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -143,6 +144,8 @@ impl Component for NavbarDropdownItem {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(NAVBAR_STYLES);
+
         Self { link, props }
     }
 
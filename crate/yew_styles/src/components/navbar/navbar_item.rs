@@ -1,3 +1,4 @@
+use crate::embedded_styles::{mount_styles, NAVBAR_STYLES};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use web_sys::window;
@@ -137,6 +138,8 @@ impl Component for NavbarItem {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(NAVBAR_STYLES);
+
         NavbarItem { link, props }
     }
 
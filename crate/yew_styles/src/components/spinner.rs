@@ -1,4 +1,7 @@
-use crate::styles::{get_palette, get_size, Palette, Size};
+use crate::embedded_styles::{mount_styles, SPINNER_STYLES};
+use crate::styles::{get_palette, get_size, Palette, Size, Style};
+use crate::theme::{get_theme_color, ThemeColor};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -76,7 +79,7 @@ pub struct Props {
     /// Type spinner palette. Default `Palette::Standard`
     #[prop_or(Palette::Standard)]
     pub spinner_palette: Palette,
-    /// Three diffent spinner standard sizes. Default `Size::Medium`
+    /// Five diffent spinner standard sizes or a custom scale. Default `Size::Medium`
     #[prop_or(Size::Medium)]
     pub spinner_size: Size,
     /// General property to get the ref of the component
@@ -101,6 +104,8 @@ impl Component for Spinner {
     type Properties = Props;
 
     fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        mount_styles(SPINNER_STYLES);
+
         Self { props }
     }
 
@@ -157,7 +162,17 @@ fn render_spinner_type(spinner_type: &str, dots: u8, props: Props) -> Html {
     }
     html! {
         <div
-            class=classes!(spinner_type.to_owned(), get_palette(props.spinner_palette), get_size(props.spinner_size), props.class_name, props.styles)
+            class=classes!(
+                spinner_type.to_owned(),
+                get_palette(props.spinner_palette.clone()),
+                get_size(props.spinner_size.clone()),
+                StyleSource::from(get_custom_size_css(props.spinner_size, |scale| {
+                    format!("&.custom {{ --sk-size: calc(40px * {}); }}", scale)
+                })),
+                StyleSource::from(get_spinner_css(spinner_type, props.spinner_palette)),
+                props.class_name,
+                props.styles
+            )
             ref=props.code_ref
             id=props.id
             key=props.key
@@ -167,6 +182,56 @@ fn render_spinner_type(spinner_type: &str, dots: u8, props: Props) -> Html {
     }
 }
 
+fn get_spinner_css(spinner_type: &str, palette: Palette) -> String {
+    let background = get_theme_color(palette, Style::Regular, ThemeColor::Background);
+
+    if spinner_type == "sk-plane" || spinner_type == "sk-pulse" {
+        format!("background: {};", background)
+    } else {
+        format!(
+            "div[class$=\"-dot\"]:before,
+            div[class$=\"-rect\"]:before,
+            div[class$=\"-cube\"]:before,
+            .sk-grid-cube,
+            .sk-wander-cube,
+            .sk-wave-rect,
+            .sk-swing-dot,
+            .sk-bounce-dot,
+            .sk-flow-dot {{
+                background: {};
+            }}",
+            background
+        )
+    }
+}
+
+/// Spinner centered over the content of a component, it takes the size of its text
+/// and the text color of the palette in the style
+pub(crate) fn get_inline_spinner(
+    loading: bool,
+    spinner_type: SpinnerType,
+    palette: Palette,
+    style: Style,
+) -> Html {
+    if loading {
+        html! {
+            <span
+                class=classes!(
+                    "inline-spinner",
+                    StyleSource::from(format!(
+                        "color: {};",
+                        get_theme_color(palette, style, ThemeColor::Color)
+                    ))
+                )
+            >
+                <Spinner spinner_type=spinner_type />
+            </span>
+        }
+    } else {
+        html! {}
+    }
+}
+
 wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
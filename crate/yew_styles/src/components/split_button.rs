@@ -0,0 +1,339 @@
+use crate::button::{Button, ButtonIcon, IconOnly};
+use crate::dropdown::{get_dropdown_css, DropdownItem};
+use crate::embedded_styles::{mount_styles, SPLIT_BUTTON_STYLES};
+use crate::styles::{Palette, Size, Style};
+use stylist::{css, StyleSource};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{HtmlElement, Node};
+use yew::html::ChildrenRenderer;
+use yew::prelude::*;
+use yew::{utils, App};
+use yew_assets::controller_assets::ControllerIcon;
+
+/// # Split Button component
+///
+/// Main action button with a caret that opens a menu with secondary actions.
+/// The menu is closed after selecting an item, pressing escape or moving the focus out of the component
+///
+/// ## Features required
+///
+/// split_button
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::{
+///     dropdown::DropdownItem,
+///     split_button::SplitButton,
+///     styles::{Palette, Style},
+/// };
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+/// }
+///
+/// pub enum Msg {
+///     Save,
+///     SaveAs,
+///     SaveAndClose,
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App { link }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::Save => {}
+///             Msg::SaveAs => {}
+///             Msg::SaveAndClose => {}
+///         }
+///         false
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///         html! {
+///             <SplitButton
+///                 main_content=html! {<span>{"Save"}</span>}
+///                 button_palette=Palette::Primary
+///                 button_style=Style::Regular
+///                 onclick_signal=self.link.callback(|_| Msg::Save)
+///             >
+///                 <DropdownItem onclick_signal=self.link.callback(|_| Msg::SaveAs)>{"Save as..."}</DropdownItem>
+///                 <DropdownItem onclick_signal=self.link.callback(|_| Msg::SaveAndClose)>{"Save and close"}</DropdownItem>
+///             </SplitButton>
+///         }
+///     }
+/// }
+/// ```
+pub struct SplitButton {
+    link: ComponentLink<Self>,
+    props: Props,
+    open: bool,
+    toggle_ref: NodeRef,
+}
+
+pub enum Msg {
+    ToggleMenu,
+    SelectItem,
+    KeyPressed(KeyboardEvent),
+    FocusOut(FocusEvent),
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Content of the main button. Required
+    pub main_content: Html,
+    /// Type palette of the buttons and the menu. Default `Palette::Standard`
+    #[prop_or(Palette::Standard)]
+    pub button_palette: Palette,
+    /// Style of the buttons and the menu. Default `Style::Regular`
+    #[prop_or(Style::Regular)]
+    pub button_style: Style,
+    /// Size of the buttons. Default `Size::Medium`
+    #[prop_or(Size::Medium)]
+    pub button_size: Size,
+    /// Accessible label of the caret that opens the menu. Default `"More actions"`
+    #[prop_or("More actions".to_string())]
+    pub menu_label: String,
+    /// Whether the buttons are disabled. Default `false`
+    #[prop_or(false)]
+    pub disabled: bool,
+    /// Click event for the main button
+    #[prop_or(Callback::noop())]
+    pub onclick_signal: Callback<MouseEvent>,
+    /// Signal when the menu is opened or closed with the new state
+    #[prop_or(Callback::noop())]
+    pub ontoggle_signal: Callback<bool>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    /// Secondary actions showed in the menu
+    pub children: ChildrenWithProps<DropdownItem>,
+}
+
+impl Component for SplitButton {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(SPLIT_BUTTON_STYLES);
+
+        SplitButton {
+            link,
+            props,
+            open: false,
+            toggle_ref: NodeRef::default(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ToggleMenu => {
+                self.open = !self.open;
+
+                if let Some(toggle) = self.toggle_ref.cast::<HtmlElement>() {
+                    toggle.focus().ok();
+                }
+            }
+            Msg::SelectItem => {
+                self.open = false;
+            }
+            Msg::KeyPressed(keyboard_event) => {
+                if keyboard_event.key() != "Escape" || !self.open {
+                    return false;
+                }
+
+                self.open = false;
+
+                if let Some(toggle) = self.toggle_ref.cast::<HtmlElement>() {
+                    toggle.focus().ok();
+                }
+            }
+            Msg::FocusOut(focus_event) => {
+                let focus_inside = match (
+                    focus_event.related_target(),
+                    self.props.code_ref.cast::<Node>(),
+                ) {
+                    (Some(target), Some(split_button)) => target
+                        .dyn_into::<Node>()
+                        .map_or(false, |node| split_button.contains(Some(&node))),
+                    _ => false,
+                };
+
+                if focus_inside || !self.open {
+                    return false;
+                }
+
+                self.open = false;
+            }
+        };
+
+        self.props.ontoggle_signal.emit(self.open);
+
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if props.disabled {
+                self.open = false;
+            }
+
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=classes!(
+                    "split-button",
+                    if self.open { "open" } else { "" },
+                    self.props.class_name.clone(),
+                    self.props.styles.clone()
+                )
+                id=self.props.id.clone()
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+                onkeydown=self.link.callback(Msg::KeyPressed)
+                onfocusout=self.link.callback(Msg::FocusOut)
+            >
+                <Button
+                    class_name="split-button-main"
+                    button_palette=self.props.button_palette.clone()
+                    button_style=self.props.button_style.clone()
+                    button_size=self.props.button_size.clone()
+                    disabled=self.props.disabled
+                    onclick_signal=self.props.onclick_signal.clone()
+                >{self.props.main_content.clone()}</Button>
+                <Button
+                    class_name="split-button-toggle"
+                    code_ref=self.toggle_ref.clone()
+                    button_palette=self.props.button_palette.clone()
+                    button_style=self.props.button_style.clone()
+                    button_size=self.props.button_size.clone()
+                    disabled=self.props.disabled
+                    aria_haspopup=Some("menu".to_string())
+                    aria_expanded=Some(self.open)
+                    icon_only=Some(IconOnly::new(
+                        ButtonIcon::Controller(ControllerIcon::ChevronDown),
+                        &self.props.menu_label
+                    ))
+                    onclick_signal=self.link.callback(|_| Msg::ToggleMenu)
+                />
+                {if self.open {
+                    html! {
+                        <ul
+                            class=classes!(
+                                "split-button-menu",
+                                StyleSource::from(get_dropdown_css(
+                                    self.props.button_palette.clone(),
+                                    self.props.button_style.clone()
+                                ))
+                            )
+                            role="menu"
+                            tabindex="-1"
+                        >
+                            {self.props.children.iter().map(|mut item| {
+                                let onclick_signal = item.props.onclick_signal.clone();
+
+                                item.props.role = Some("menuitem".to_string());
+
+                                item.props.onclick_signal = self.link.callback(move |mouse_event: MouseEvent| {
+                                    onclick_signal.emit(mouse_event);
+                                    Msg::SelectItem
+                                });
+
+                                item
+                            }).collect::<Html>()}
+                        </ul>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_open_the_menu_when_the_caret_is_clicked() {
+    let props = Props {
+        main_content: html! {<span id="split-main">{"Save"}</span>},
+        button_palette: Palette::Primary,
+        button_style: Style::Regular,
+        button_size: Size::Medium,
+        menu_label: "More actions".to_string(),
+        disabled: false,
+        onclick_signal: Callback::noop(),
+        ontoggle_signal: Callback::noop(),
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "split-button-test".to_string(),
+        id: "split-button-id-test".to_string(),
+        styles: css!(""),
+        children: ChildrenRenderer::new(vec![]),
+    };
+
+    let split_button: App<SplitButton> = App::new();
+    split_button.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let split_button_element = utils::document()
+        .get_element_by_id("split-button-id-test")
+        .unwrap();
+    let main_element = utils::document().get_element_by_id("split-main").unwrap();
+    let toggle_element = split_button_element
+        .get_elements_by_class_name("split-button-toggle")
+        .get_with_index(0)
+        .unwrap();
+
+    assert_eq!(main_element.text_content().unwrap(), "Save".to_string());
+    assert_eq!(
+        toggle_element.get_attribute("aria-haspopup"),
+        Some("menu".to_string())
+    );
+    assert_eq!(
+        toggle_element.get_attribute("aria-expanded"),
+        Some("false".to_string())
+    );
+
+    toggle_element.dyn_into::<HtmlElement>().unwrap().click();
+
+    let menu_element = split_button_element
+        .get_elements_by_class_name("split-button-menu")
+        .get_with_index(0);
+
+    assert!(split_button_element.class_list().contains("open"));
+    assert!(menu_element.is_some());
+}
@@ -1,4 +1,6 @@
+use crate::embedded_styles::{mount_styles, TEXT_STYLES};
 use crate::styles::{get_palette, get_size, get_style, Palette, Size, Style};
+use crate::theme::{get_font_size_css, get_palette_css};
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -231,7 +233,7 @@ pub struct Props {
     /// Text styles. Only for tag and alert type. Default `Style::Regular`
     #[prop_or(Style::Regular)]
     pub text_style: Style,
-    /// Three diffent text standard sizes. Not for title type. Default `Size::Medium`
+    /// Five diffent text standard sizes or a custom scale. Not for title type. Default `Size::Medium`
     #[prop_or(Size::Medium)]
     pub text_size: Size,
     /// General property to get the ref of the component
@@ -269,6 +271,8 @@ impl Component for Text {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(TEXT_STYLES);
+
         Self { link, props }
     }
 
@@ -339,7 +343,13 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
         TextType::Plain => {
             html! {
                 <span
-                    class=classes!("plain-text", get_size(props.text_size), props.class_name, props.styles)
+                    class=classes!(
+                        "plain-text",
+                        get_size(props.text_size.clone()),
+                        StyleSource::from(get_font_size_css(props.text_size)),
+                        props.class_name,
+                        props.styles
+                    )
                     id=props.id
                     key=props.key
                     ref=props.code_ref
@@ -349,7 +359,13 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
         TextType::Paragraph => {
             html! {
                 <p
-                    class=classes!("paragraph-text", get_size(props.text_size), props.class_name, props.styles)
+                    class=classes!(
+                        "paragraph-text",
+                        get_size(props.text_size.clone()),
+                        StyleSource::from(get_font_size_css(props.text_size)),
+                        props.class_name,
+                        props.styles
+                    )
                     id=props.id
                     key=props.key
                     ref=props.code_ref
@@ -361,9 +377,14 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
                 <div
                     class=classes!(
                         "alert-text",
-                        get_style(props.text_style),
-                        get_palette(props.text_palette),
-                        get_size(props.text_size),
+                        get_style(props.text_style.clone()),
+                        get_palette(props.text_palette.clone()),
+                        get_size(props.text_size.clone()),
+                        StyleSource::from(format!(
+                            "{}{}",
+                            get_palette_css(props.text_palette, props.text_style, false),
+                            get_font_size_css(props.text_size)
+                        )),
                         props.class_name,
                         props.styles
                     )
@@ -385,9 +406,18 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
                         } else {
                             ""
                         },
-                        get_style(props.text_style),
-                        get_palette(props.text_palette),
+                        get_style(props.text_style.clone()),
+                        get_palette(props.text_palette.clone()),
                         get_size(props.text_size.clone()),
+                        StyleSource::from(format!(
+                            "{}{}",
+                            get_palette_css(
+                                props.text_palette,
+                                props.text_style,
+                                props.interaction_effect
+                            ),
+                            get_font_size_css(props.text_size.clone())
+                        )),
                         props.class_name,
                         props.styles,
                     )
@@ -414,13 +444,7 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
                             >
                                 <EditingAssets
                                     icon=EditingIcon::X
-                                    size=if props.text_size == Size::Medium {
-                                        ("20".to_string(), "20".to_string())
-                                    } else if props.text_size == Size::Small {
-                                        ("13".to_string(), "13".to_string())
-                                    } else {
-                                        ("24".to_string(), "24".to_string())
-                                    }
+                                    size=get_delete_icon_size(props.text_size.clone())
                                 />
                             </div>
                         }
@@ -434,6 +458,19 @@ fn get_text(text_type: TextType, props: Props, link: ComponentLink<Text>) -> Htm
     }
 }
 
+fn get_delete_icon_size(size: Size) -> (String, String) {
+    let icon_size = match size {
+        Size::XSmall => 10.0,
+        Size::Small => 13.0,
+        Size::Medium => 20.0,
+        Size::Big => 24.0,
+        Size::XLarge => 30.0,
+        Size::Custom(scale) => (20.0 * scale).round(),
+    };
+
+    (icon_size.to_string(), icon_size.to_string())
+}
+
 fn get_header(header: Header, props: Props) -> Html {
     match header {
         Header::H1 => html! {<h1
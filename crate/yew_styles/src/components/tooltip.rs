@@ -1,4 +1,7 @@
+use crate::embedded_styles::{mount_styles, TOOLTIP_STYLES};
 use crate::styles::{get_palette, get_size, get_style, Palette, Position, Size, Style};
+use crate::theme::{get_font_size_css, get_palette_css, get_theme_color, ThemeColor};
+use crate::utils::get_custom_size_css;
 use stylist::{css, StyleSource};
 use wasm_bindgen_test::*;
 use yew::prelude::*;
@@ -102,6 +105,8 @@ impl Component for Tooltip {
     type Properties = Props;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        mount_styles(TOOLTIP_STYLES);
+
         Self {
             props,
             link,
@@ -138,6 +143,12 @@ impl Component for Tooltip {
                     get_palette(self.props.tooltip_palette.clone()),
                     get_style(self.props.tooltip_style.clone()),
                     get_size(self.props.tooltip_size.clone()),
+                    StyleSource::from(get_tooltip_css(
+                        self.props.tooltip_palette.clone(),
+                        self.props.tooltip_style.clone(),
+                        self.props.tooltip_size.clone(),
+                        self.props.tooltip_position.clone(),
+                    )),
                     self.props.class_name.clone(),
                     self.props.styles.clone()
                 )
@@ -171,6 +182,49 @@ fn get_position(position: Position) -> String {
     }
 }
 
+fn get_tooltip_css(palette: Palette, style: Style, size: Size, position: Position) -> String {
+    // The arrow continues the border of the outline tooltips and the background of the rest
+    let arrow_color = match style {
+        Style::Outline => get_theme_color(palette.clone(), Style::Outline, ThemeColor::BorderColor),
+        _ => get_theme_color(palette.clone(), style.clone(), ThemeColor::Background),
+    };
+    let get_arrow_border = |position: Position| match position {
+        Position::Left => format!("transparent transparent transparent {}", arrow_color),
+        Position::Right => format!("transparent {} transparent transparent", arrow_color),
+        Position::Above => format!("{} transparent transparent transparent", arrow_color),
+        Position::Below => format!("transparent transparent {} transparent", arrow_color),
+    };
+    let mirrored_position = match position.clone() {
+        Position::Left => Position::Right,
+        Position::Right => Position::Left,
+        position => position,
+    };
+
+    format!(
+        "{}{}{}
+        &.{position}::after {{
+            content: \" \";
+            position: absolute;
+            border-style: solid;
+            border-width: 5px;
+            border-color: {};
+        }}
+        &.{position}:dir(rtl)::after {{
+            border-color: {};
+        }}",
+        get_palette_css(palette, style, true),
+        get_font_size_css(size.clone()),
+        get_custom_size_css(size, |scale| format!(
+            "&.custom.above {{ top: calc(var(--tooltip-margin) * -1.2 * {0}); }}
+            &.custom.below {{ bottom: calc(var(--tooltip-margin) * -1.2 * {0}); }}",
+            scale
+        )),
+        get_arrow_border(position.clone()),
+        get_arrow_border(mirrored_position),
+        position = get_position(position),
+    )
+}
+
 #[wasm_bindgen_test]
 fn should_create_tooltip() {
     let tooltip_props = Props {
@@ -203,3 +257,28 @@ fn should_create_tooltip() {
 
     assert_eq!(child.id(), "result".to_string());
 }
+
+#[wasm_bindgen_test]
+fn should_color_the_arrow_with_the_style_of_the_tooltip() {
+    let outline_css = get_tooltip_css(
+        Palette::Primary,
+        Style::Outline,
+        Size::Medium,
+        Position::Above,
+    );
+    let light_css = get_tooltip_css(
+        Palette::Primary,
+        Style::Light,
+        Size::Medium,
+        Position::Above,
+    );
+
+    assert!(outline_css.contains(&format!(
+        "border-color: {} transparent transparent transparent;",
+        get_theme_color(Palette::Primary, Style::Outline, ThemeColor::BorderColor)
+    )));
+    assert!(light_css.contains(&format!(
+        "border-color: {} transparent transparent transparent;",
+        get_theme_color(Palette::Primary, Style::Light, ThemeColor::Background)
+    )));
+}
@@ -0,0 +1,49 @@
+//! Base css of each component. With the feature `embedded-styles` it is injected
+//! with stylist so the sass module `yew-styles` is not needed
+#[cfg(feature = "embedded-styles")]
+use stylist::GlobalStyle;
+#[cfg(feature = "embedded-styles")]
+use yew::services::ConsoleService;
+
+#[cfg(feature = "app_shell")]
+pub const APP_SHELL_STYLES: &str = include_str!("app_shell.css");
+#[cfg(feature = "button")]
+pub const BUTTON_STYLES: &str = include_str!("button.css");
+#[cfg(feature = "button_group")]
+pub const BUTTON_GROUP_STYLES: &str = include_str!("button_group.css");
+#[cfg(feature = "card")]
+pub const CARD_STYLES: &str = include_str!("card.css");
+#[cfg(feature = "carousel")]
+pub const CAROUSEL_STYLES: &str = include_str!("carousel.css");
+#[cfg(feature = "dropdown")]
+pub const DROPDOWN_STYLES: &str = include_str!("dropdown.css");
+#[cfg(feature = "forms")]
+pub const FORMS_STYLES: &str = include_str!("forms.css");
+#[cfg(feature = "layouts")]
+pub const LAYOUTS_STYLES: &str = include_str!("layouts.css");
+#[cfg(feature = "modal")]
+pub const MODAL_STYLES: &str = include_str!("modal.css");
+#[cfg(feature = "navbar")]
+pub const NAVBAR_STYLES: &str = include_str!("navbar.css");
+#[cfg(feature = "spinner")]
+pub const SPINNER_STYLES: &str = include_str!("spinner.css");
+#[cfg(feature = "split_button")]
+pub const SPLIT_BUTTON_STYLES: &str = include_str!("split_button.css");
+#[cfg(feature = "text")]
+pub const TEXT_STYLES: &str = include_str!("text.css");
+#[cfg(feature = "tooltip")]
+pub const TOOLTIP_STYLES: &str = include_str!("tooltip.css");
+
+/// Inject the base css of the component in the document.
+/// Stylist keeps only one copy of the same css no matter how many times it is mounted.
+/// If stylist can not parse the css the error is logged and the component is rendered without it
+#[cfg(feature = "embedded-styles")]
+pub fn mount_styles(styles: &'static str) {
+    if let Err(error) = GlobalStyle::new(styles) {
+        ConsoleService::error(&format!("Failed to mount the component styles: {}", error));
+    }
+}
+
+/// Without the feature `embedded-styles` the styles come from the sass module
+#[cfg(not(feature = "embedded-styles"))]
+pub fn mount_styles(_styles: &'static str) {}
@@ -25,6 +25,31 @@
 //! ```
 //! 4. Ready to import and use in your project 🚀
 //!
+//! ### Without npm
+//! Enabling the feature `embedded-styles`, the base css of each component is injected with stylist,
+//! then the sass module and the `main.css` import are not needed.
+//! Only the styles of the component features enabled are included:
+//! ```toml
+//! yew_styles = { version="0.11", features=["button", "text", "navbar", "embedded-styles"] }
+//! ```
+//!
+//! ### Theme
+//! The colors and font sizes of the components are css variables set by the theme.
+//! To change them, wrap the components with `ThemeProvider` and pass a custom `Theme`,
+//! it can be updated in runtime without mount again the components:
+//! ```rust
+//! use yew_styles::theme::{StyleColors, Theme, ThemeProvider};
+//!
+//! let mut theme = Theme::default();
+//! theme.primary.regular = StyleColors::new("#1d3557", "#fff", None);
+//!
+//! html! {
+//!     <ThemeProvider theme=theme>
+//!         // components
+//!     </ThemeProvider>
+//! };
+//! ```
+//!
 //! ### Yew styles with Trunk
 //! To create an app with Yew Styles framework using [Trunk](https://trunkrs.dev/) instead of `Parcel` is possible, please check [Yew Styles Trunk Template](https://github.com/dancespiele/yew_styles_trunk_template)
 //!
@@ -58,11 +83,17 @@
 //! * Create a component page in `/crate/src/page` with the same structure than the rest of the components
 #![recursion_limit = "512"]
 mod components;
+mod embedded_styles;
 pub mod styles;
+pub mod theme;
 mod utils;
 
+#[cfg(feature = "app_shell")]
+pub use components::app_shell;
 #[cfg(feature = "button")]
 pub use components::button;
+#[cfg(feature = "button_group")]
+pub use components::button_group;
 #[cfg(feature = "card")]
 pub use components::card;
 #[cfg(feature = "carousel")]
@@ -79,6 +110,8 @@ pub use components::modal;
 pub use components::navbar;
 #[cfg(feature = "spinner")]
 pub use components::spinner;
+#[cfg(feature = "split_button")]
+pub use components::split_button;
 #[cfg(feature = "text")]
 pub use components::text;
 #[cfg(feature = "tooltip")]
@@ -10,6 +10,41 @@ pub enum Palette {
     Danger,
     Standard,
     Clean,
+    Custom(CustomPalette),
+}
+
+/// Custom palette with its own colors. Its styles are created from these colors
+/// so it doesn't need any sass class
+///
+/// ## Example
+///
+/// ```rust
+/// use yew_styles::styles::{CustomPalette, Palette};
+///
+/// let brand = Palette::Custom(CustomPalette::new("brand", "#1d3557", "#fff", None));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomPalette {
+    /// Name of the palette. It is used with the prefix `custom-` as class name and in the css custom
+    /// properties, characters other than `a-z`, `0-9` and `-` are replaced by `-`. Example: `brand`
+    pub name: String,
+    /// Background color in regular style and text color in outline and light style
+    pub background: String,
+    /// Text color in regular style
+    pub color: String,
+    /// Border color. Without it, only outline style has border using the background color
+    pub border_color: Option<String>,
+}
+
+impl CustomPalette {
+    pub fn new(name: &str, background: &str, color: &str, border_color: Option<&str>) -> Self {
+        CustomPalette {
+            name: name.to_string(),
+            background: background.to_string(),
+            color: color.to_string(),
+            border_color: border_color.map(|border_color| border_color.to_string()),
+        }
+    }
 }
 
 pub fn get_palette(palette: Palette) -> String {
@@ -23,15 +58,35 @@ pub fn get_palette(palette: Palette) -> String {
         Palette::Danger => String::from("danger"),
         Palette::Standard => String::from("standard"),
         Palette::Clean => String::from("clean"),
+        Palette::Custom(custom_palette) => get_custom_palette_name(&custom_palette.name),
     }
 }
 
+/// Class name of the custom palette, only with lowercase letters, numbers and `-`.
+/// Example: `Brand Blue` is `custom-brand-blue`
+fn get_custom_palette_name(name: &str) -> String {
+    let name = name
+        .trim()
+        .chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            c @ 'a'..='z' | c @ '0'..='9' | c @ '-' => c,
+            _ => '-',
+        })
+        .collect::<String>();
+
+    format!("custom-{}", name)
+}
+
 /// The standard sizes of the element
 #[derive(Clone, PartialEq)]
 pub enum Size {
+    XSmall,
     Small,
     Medium,
     Big,
+    XLarge,
+    /// Scale applied to the medium size. Example: `Size::Custom(1.5)`
+    Custom(f64),
 }
 
 /// Standars button styles
@@ -40,6 +95,21 @@ pub enum Style {
     Regular,
     Outline,
     Light,
+    /// Without background and border, it shows the light background on interaction
+    Ghost,
+    /// Only the text like a link
+    Text,
+    /// Elevated with a gradient background
+    Gradient,
+}
+
+/// Direction of the text. In right to left the components are mirrored
+#[derive(Clone, PartialEq)]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+    /// Takes the direction from the content
+    Auto,
 }
 
 /// Position over targeted element
@@ -51,11 +121,22 @@ pub enum Position {
     Below,
 }
 
+pub fn get_text_direction(text_direction: TextDirection) -> String {
+    match text_direction {
+        TextDirection::Ltr => String::from("ltr"),
+        TextDirection::Rtl => String::from("rtl"),
+        TextDirection::Auto => String::from("auto"),
+    }
+}
+
 pub fn get_size(size: Size) -> String {
     match size {
+        Size::XSmall => String::from("xsmall"),
         Size::Small => String::from("small"),
         Size::Medium => String::from("medium"),
         Size::Big => String::from("big"),
+        Size::XLarge => String::from("xlarge"),
+        Size::Custom(_) => String::from("custom"),
     }
 }
 
@@ -64,5 +145,8 @@ pub fn get_style(style: Style) -> String {
         Style::Regular => String::from("regular"),
         Style::Outline => String::from("outline"),
         Style::Light => String::from("light"),
+        Style::Ghost => String::from("ghost"),
+        Style::Text => String::from("text"),
+        Style::Gradient => String::from("gradient"),
     }
 }
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::*;
+
+/// Min width in pixels where starts each screen.
+/// The screen `xs` starts in 0. The responsive components take them in the `breakpoints` prop,
+/// usually the breakpoints of the theme
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Breakpoints {
+    pub s: u32,
+    pub m: u32,
+    pub l: u32,
+    pub xl: u32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Breakpoints {
+            s: 576,
+            m: 768,
+            l: 992,
+            xl: 1200,
+        }
+    }
+}
+
+/// Screens where the responsive props can change
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Screen {
+    Xs,
+    S,
+    M,
+    L,
+    Xl,
+}
+
+/// Value of a responsive prop applied from the breakpoint of the screen
+#[derive(Clone, Debug, PartialEq)]
+pub enum Responsive<T> {
+    Xs(T),
+    S(T),
+    M(T),
+    L(T),
+    Xl(T),
+}
+
+impl<T> Responsive<T> {
+    pub fn get_screen_value(self) -> (Screen, T) {
+        match self {
+            Responsive::Xs(value) => (Screen::Xs, value),
+            Responsive::S(value) => (Screen::S, value),
+            Responsive::M(value) => (Screen::M, value),
+            Responsive::L(value) => (Screen::L, value),
+            Responsive::Xl(value) => (Screen::Xl, value),
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Min width of the screen, `xs` doesn't have it
+    pub fn get_min_width(&self, screen: Screen) -> Option<u32> {
+        match screen {
+            Screen::Xs => None,
+            Screen::S => Some(self.s),
+            Screen::M => Some(self.m),
+            Screen::L => Some(self.l),
+            Screen::Xl => Some(self.xl),
+        }
+    }
+}
+
+/// Wrap the css in a media query from the min width of the screen
+pub fn get_screen_css(breakpoints: &Breakpoints, screen: Screen, css: &str) -> String {
+    match breakpoints.get_min_width(screen) {
+        Some(min_width) => format!("@media (min-width: {}px) {{ {} }}\n", min_width, css),
+        None => format!("{}\n", css),
+    }
+}
+
+/// Css of each value inside the media query of its screen, from the smallest screen to the biggest
+pub fn get_responsive_css<T>(
+    breakpoints: &Breakpoints,
+    values: Vec<Responsive<T>>,
+    get_css: impl Fn(T) -> String,
+) -> String {
+    let mut screen_values = values
+        .into_iter()
+        .map(|value| value.get_screen_value())
+        .collect::<Vec<(Screen, T)>>();
+
+    screen_values.sort_by(|(screen_a, _), (screen_b, _)| screen_a.cmp(screen_b));
+
+    screen_values
+        .into_iter()
+        .map(|(screen, value)| get_screen_css(breakpoints, screen, &get_css(value)))
+        .collect::<String>()
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_screen_css_from_the_breakpoints() {
+    let breakpoints = Breakpoints {
+        s: 480,
+        m: 720,
+        l: 1024,
+        xl: 1440,
+    };
+
+    assert_eq!(
+        get_screen_css(&breakpoints, Screen::M, "flex-basis: 50%;"),
+        "@media (min-width: 720px) { flex-basis: 50%; }\n"
+    );
+    assert_eq!(
+        get_screen_css(&breakpoints, Screen::Xs, "flex-basis: 50%;"),
+        "flex-basis: 50%;\n"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_get_responsive_css_sorted_by_screen() {
+    let responsive_css = get_responsive_css(
+        &Breakpoints::default(),
+        vec![Responsive::L("row"), Responsive::Xs("column")],
+        |direction| format!("flex-direction: {};", direction),
+    );
+
+    assert_eq!(
+        responsive_css,
+        "flex-direction: column;\n@media (min-width: 992px) { flex-direction: row; }\n"
+    );
+}
@@ -0,0 +1,59 @@
+use wasm_bindgen_test::*;
+use web_sys::{window, Storage};
+
+const COLOR_MODE_KEY: &str = "yew-styles-color-mode";
+
+/// Color scheme applied by the `ThemeProvider`
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColorMode {
+    /// Follows the `prefers-color-scheme` of the user
+    Auto,
+    Light,
+    Dark,
+}
+
+pub fn get_color_mode(color_mode: ColorMode) -> String {
+    match color_mode {
+        ColorMode::Auto => String::from("auto"),
+        ColorMode::Light => String::from("light"),
+        ColorMode::Dark => String::from("dark"),
+    }
+}
+
+fn get_local_storage() -> Option<Storage> {
+    window()?.local_storage().ok().flatten()
+}
+
+/// Color mode saved in the localStorage, if there is one
+pub fn get_stored_color_mode() -> Option<ColorMode> {
+    let color_mode = get_local_storage()?.get_item(COLOR_MODE_KEY).ok().flatten()?;
+
+    match color_mode.as_str() {
+        "auto" => Some(ColorMode::Auto),
+        "light" => Some(ColorMode::Light),
+        "dark" => Some(ColorMode::Dark),
+        _ => None,
+    }
+}
+
+/// Save the color mode in the localStorage to keep it in the next visits
+pub fn store_color_mode(color_mode: ColorMode) {
+    if let Some(storage) = get_local_storage() {
+        storage
+            .set_item(COLOR_MODE_KEY, &get_color_mode(color_mode))
+            .unwrap_or_default();
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_store_color_mode() {
+    store_color_mode(ColorMode::Dark);
+
+    assert_eq!(get_stored_color_mode(), Some(ColorMode::Dark));
+
+    store_color_mode(ColorMode::Auto);
+
+    assert_eq!(get_stored_color_mode(), Some(ColorMode::Auto));
+}
@@ -0,0 +1,807 @@
+mod breakpoints;
+mod color_mode;
+mod theme_provider;
+
+pub use breakpoints::{get_responsive_css, get_screen_css, Breakpoints, Responsive, Screen};
+pub use color_mode::{get_color_mode, get_stored_color_mode, store_color_mode, ColorMode};
+pub use theme_provider::ThemeProvider;
+
+use crate::styles::{get_palette, get_size, get_style, CustomPalette, Palette, Size, Style};
+use crate::utils::{darken_color, lighten_color, transparentize_color};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen_test::*;
+
+/// Background, text and border colors of a palette in one style
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StyleColors {
+    pub background: String,
+    pub color: String,
+    /// Without border color the style doesn't have border
+    pub border_color: Option<String>,
+}
+
+impl StyleColors {
+    pub fn new(background: &str, color: &str, border_color: Option<&str>) -> Self {
+        StyleColors {
+            background: background.to_string(),
+            color: color.to_string(),
+            border_color: border_color.map(|border_color| border_color.to_string()),
+        }
+    }
+}
+
+/// Colors of a palette for each style
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaletteColors {
+    pub regular: StyleColors,
+    pub outline: StyleColors,
+    pub light: StyleColors,
+}
+
+impl PaletteColors {
+    /// Colors of a custom palette. The outline style uses the surface color as background,
+    /// the background of the regular style of the clean palette
+    pub fn from_custom_palette(custom_palette: CustomPalette, surface: &str) -> Self {
+        let outline_border = custom_palette
+            .border_color
+            .clone()
+            .unwrap_or_else(|| custom_palette.background.clone());
+
+        PaletteColors {
+            regular: StyleColors {
+                background: custom_palette.background.clone(),
+                color: custom_palette.color.clone(),
+                border_color: custom_palette.border_color.clone(),
+            },
+            outline: StyleColors {
+                background: surface.to_string(),
+                color: custom_palette.background.clone(),
+                border_color: Some(outline_border),
+            },
+            light: StyleColors {
+                background: lighten_color(&custom_palette.background, 40.0),
+                color: custom_palette.background,
+                border_color: None,
+            },
+        }
+    }
+}
+
+/// Font size for each size
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FontSizes {
+    pub xsmall: String,
+    pub small: String,
+    pub medium: String,
+    pub big: String,
+    pub xlarge: String,
+}
+
+/// Space between and around the components for each size
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Spacings {
+    pub xsmall: String,
+    pub small: String,
+    pub medium: String,
+    pub big: String,
+    pub xlarge: String,
+}
+
+/// # Theme
+///
+/// Colors, font sizes and spacings used by the components.
+/// By default it has the colors and sizes of yew styles,
+/// to apply a custom theme use the `ThemeProvider` component
+///
+/// ## Example
+///
+/// ```rust
+/// use yew_styles::theme::{StyleColors, Theme};
+///
+/// let mut theme = Theme::default();
+///
+/// theme.primary.regular = StyleColors::new("#1d3557", "#fff", None);
+/// theme.primary.outline = StyleColors::new("#fff", "#1d3557", Some("#1d3557"));
+/// theme.font_sizes.medium = "16px".to_string();
+/// theme.spacings.small = "6px".to_string();
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Theme {
+    pub standard: PaletteColors,
+    pub primary: PaletteColors,
+    pub secondary: PaletteColors,
+    pub success: PaletteColors,
+    pub info: PaletteColors,
+    pub link: PaletteColors,
+    pub warning: PaletteColors,
+    pub danger: PaletteColors,
+    pub clean: PaletteColors,
+    pub font_sizes: FontSizes,
+    pub spacings: Spacings,
+}
+
+/// Color of the theme that a component can use
+#[derive(Clone, Debug, PartialEq)]
+pub enum ThemeColor {
+    Background,
+    Color,
+    Border,
+    BorderColor,
+    BackgroundFocus,
+    BackgroundHover,
+    BackgroundActive,
+    BorderFocus,
+    BorderHover,
+    BorderActive,
+    Overlay,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            standard: PaletteColors {
+                regular: StyleColors::new("#918d94", "#fff", None),
+                outline: StyleColors::new("#fff", "#918d94", Some("#918d94")),
+                light: StyleColors::new("#faf3f3", "#918d94", None),
+            },
+            primary: PaletteColors {
+                regular: StyleColors::new("#654016", "#fff", None),
+                outline: StyleColors::new("#fff", "#654016", Some("#654016")),
+                light: StyleColors::new("#e9d7c4", "#654016", None),
+            },
+            secondary: PaletteColors {
+                regular: StyleColors::new("#c77b21", "#fff", None),
+                outline: StyleColors::new("#fff", "#c77b21", Some("#c77b21")),
+                light: StyleColors::new("#ffd9ac", "#c77b21", None),
+            },
+            success: PaletteColors {
+                regular: StyleColors::new("#40c600", "#fff", None),
+                outline: StyleColors::new("#fff", "#40c600", Some("#40c600")),
+                light: StyleColors::new("#b6f5c6", "#1ca53e", None),
+            },
+            info: PaletteColors {
+                regular: StyleColors::new("#008fd5", "#fff", None),
+                outline: StyleColors::new("#fff", "#008fd5", Some("#008fd5")),
+                light: StyleColors::new("#cedaff", "#008fd5", None),
+            },
+            link: PaletteColors {
+                regular: StyleColors::new("#034da1", "#fff", None),
+                outline: StyleColors::new("#fff", "#034da1", Some("#034da1")),
+                light: StyleColors::new("#4fb0ff", "#034da1", None),
+            },
+            warning: PaletteColors {
+                regular: StyleColors::new("#fff200", "#000", None),
+                outline: StyleColors::new("#fff", "#e6bd44", Some("#e6bd44")),
+                light: StyleColors::new("#fdffa8", "#99a034", None),
+            },
+            danger: PaletteColors {
+                regular: StyleColors::new("#ed1c24", "#fff", None),
+                outline: StyleColors::new("#fff", "#ed1c24", Some("#ed1c24")),
+                light: StyleColors::new("#fdc5c5", "#ed1c24", None),
+            },
+            clean: PaletteColors {
+                regular: StyleColors::new("#fff", "#313131", None),
+                outline: StyleColors::new("#fff", "#313131", Some("#313131")),
+                light: StyleColors::new("#fff", "#313131", None),
+            },
+            font_sizes: FontSizes {
+                xsmall: "10px".to_string(),
+                small: "12px".to_string(),
+                medium: "18px".to_string(),
+                big: "26px".to_string(),
+                xlarge: "34px".to_string(),
+            },
+            spacings: Spacings {
+                xsmall: "4px".to_string(),
+                small: "8px".to_string(),
+                medium: "16px".to_string(),
+                big: "24px".to_string(),
+                xlarge: "32px".to_string(),
+            },
+        }
+    }
+}
+
+impl Theme {
+    /// Dark version of the default theme
+    pub fn dark() -> Self {
+        Theme {
+            standard: PaletteColors {
+                regular: StyleColors::new("#6e6a72", "#fff", None),
+                outline: StyleColors::new("#1f1f22", "#b5b1b8", Some("#b5b1b8")),
+                light: StyleColors::new("#3a383d", "#d4d0d7", None),
+            },
+            primary: PaletteColors {
+                regular: StyleColors::new("#8a5a24", "#fff", None),
+                outline: StyleColors::new("#1f1f22", "#d19a5b", Some("#d19a5b")),
+                light: StyleColors::new("#3d2a14", "#e9d7c4", None),
+            },
+            secondary: PaletteColors {
+                regular: StyleColors::new("#c77b21", "#fff", None),
+                outline: StyleColors::new("#1f1f22", "#e8a04a", Some("#e8a04a")),
+                light: StyleColors::new("#4a3012", "#ffd9ac", None),
+            },
+            success: PaletteColors {
+                regular: StyleColors::new("#38a800", "#fff", None),
+                outline: StyleColors::new("#1f1f22", "#6fe030", Some("#6fe030")),
+                light: StyleColors::new("#1d3d24", "#b6f5c6", None),
+            },
+            info: PaletteColors {
+                regular: StyleColors::new("#0079b5", "#fff", None),
+                outline: StyleColors::new("#1f1f22", "#4fb8ee", Some("#4fb8ee")),
+                light: StyleColors::new("#14304a", "#cedaff", None),
+            },
+            link: PaletteColors {
+                regular: StyleColors::new("#2f6fc0", "#fff", None),
+                outline: StyleColors::new("#1f1f22", "#6fa8ff", Some("#6fa8ff")),
+                light: StyleColors::new("#172a45", "#4fb0ff", None),
+            },
+            warning: PaletteColors {
+                regular: StyleColors::new("#d9ce00", "#000", None),
+                outline: StyleColors::new("#1f1f22", "#e6bd44", Some("#e6bd44")),
+                light: StyleColors::new("#3f3d10", "#fdffa8", None),
+            },
+            danger: PaletteColors {
+                regular: StyleColors::new("#d0161d", "#fff", None),
+                outline: StyleColors::new("#1f1f22", "#ff5a5f", Some("#ff5a5f")),
+                light: StyleColors::new("#471618", "#fdc5c5", None),
+            },
+            clean: PaletteColors {
+                regular: StyleColors::new("#1f1f22", "#e8e6e3", None),
+                outline: StyleColors::new("#1f1f22", "#e8e6e3", Some("#e8e6e3")),
+                light: StyleColors::new("#1f1f22", "#e8e6e3", None),
+            },
+            font_sizes: FontSizes {
+                xsmall: "10px".to_string(),
+                small: "12px".to_string(),
+                medium: "18px".to_string(),
+                big: "26px".to_string(),
+                xlarge: "34px".to_string(),
+            },
+            spacings: Spacings {
+                xsmall: "4px".to_string(),
+                small: "8px".to_string(),
+                medium: "16px".to_string(),
+                big: "24px".to_string(),
+                xlarge: "32px".to_string(),
+            },
+        }
+    }
+
+    /// Colors of the palette for each style
+    pub fn get_palette_colors(&self, palette: Palette) -> PaletteColors {
+        match palette {
+            Palette::Standard => self.standard.clone(),
+            Palette::Primary => self.primary.clone(),
+            Palette::Secondary => self.secondary.clone(),
+            Palette::Success => self.success.clone(),
+            Palette::Info => self.info.clone(),
+            Palette::Link => self.link.clone(),
+            Palette::Warning => self.warning.clone(),
+            Palette::Danger => self.danger.clone(),
+            Palette::Clean => self.clean.clone(),
+            Palette::Custom(custom_palette) => PaletteColors::from_custom_palette(
+                custom_palette,
+                &format!(
+                    "var({}, {})",
+                    get_color_variable(Palette::Clean, Style::Regular, ThemeColor::Background),
+                    self.clean.regular.background
+                ),
+            ),
+        }
+    }
+
+    /// Colors of the palette in the style.
+    /// Ghost, text and gradient styles are created from the colors of the rest of styles
+    pub fn get_style_colors(&self, palette: Palette, style: Style) -> StyleColors {
+        let palette_colors = self.get_palette_colors(palette);
+
+        match style {
+            Style::Regular => palette_colors.regular,
+            Style::Outline => palette_colors.outline,
+            Style::Light => palette_colors.light,
+            Style::Ghost | Style::Text => StyleColors {
+                background: "transparent".to_string(),
+                color: palette_colors.outline.color,
+                border_color: None,
+            },
+            Style::Gradient => StyleColors {
+                background: get_gradient(&palette_colors.regular.background, 0.0),
+                color: palette_colors.regular.color,
+                border_color: None,
+            },
+        }
+    }
+
+    /// Value of the theme color of the palette in the style
+    pub fn get_color(&self, palette: Palette, style: Style, theme_color: ThemeColor) -> String {
+        let palette_colors = self.get_palette_colors(palette.clone());
+
+        match (&style, &theme_color) {
+            (Style::Ghost, ThemeColor::BackgroundFocus) => {
+                return palette_colors.light.background;
+            }
+            (Style::Ghost, ThemeColor::BackgroundHover) => {
+                return darken_color(&palette_colors.light.background, 5.0);
+            }
+            (Style::Ghost, ThemeColor::BackgroundActive) => {
+                return darken_color(&palette_colors.light.background, 10.0);
+            }
+            (Style::Text, ThemeColor::BackgroundFocus)
+            | (Style::Text, ThemeColor::BackgroundHover)
+            | (Style::Text, ThemeColor::BackgroundActive) => {
+                return "transparent".to_string();
+            }
+            (Style::Gradient, ThemeColor::BackgroundFocus) => {
+                return get_gradient(&palette_colors.regular.background, 5.0);
+            }
+            (Style::Gradient, ThemeColor::BackgroundHover) => {
+                return get_gradient(&palette_colors.regular.background, 10.0);
+            }
+            (Style::Gradient, ThemeColor::BackgroundActive) => {
+                return get_gradient(&palette_colors.regular.background, 15.0);
+            }
+            (Style::Ghost, ThemeColor::Overlay) | (Style::Text, ThemeColor::Overlay) => {
+                return transparentize_color(&palette_colors.light.background, 0.4);
+            }
+            (Style::Gradient, ThemeColor::Overlay) => {
+                return transparentize_color(&palette_colors.regular.background, 0.4);
+            }
+            _ => {}
+        }
+
+        let style_colors = self.get_style_colors(palette, style);
+        let border_color = style_colors
+            .border_color
+            .clone()
+            .unwrap_or_else(|| style_colors.background.clone());
+
+        match theme_color {
+            ThemeColor::Background => style_colors.background,
+            ThemeColor::Color => style_colors.color,
+            ThemeColor::Border => match style_colors.border_color {
+                Some(border_color) => format!("1px solid {}", border_color),
+                None => "none".to_string(),
+            },
+            ThemeColor::BorderColor => border_color,
+            ThemeColor::BackgroundFocus => darken_color(&style_colors.background, 5.0),
+            ThemeColor::BackgroundHover => darken_color(&style_colors.background, 10.0),
+            ThemeColor::BackgroundActive => darken_color(&style_colors.background, 15.0),
+            ThemeColor::BorderFocus => darken_color(&border_color, 10.0),
+            ThemeColor::BorderHover => darken_color(&border_color, 20.0),
+            ThemeColor::BorderActive => darken_color(&border_color, 30.0),
+            ThemeColor::Overlay => transparentize_color(&style_colors.background, 0.4),
+        }
+    }
+
+    /// Font size of the theme for the size
+    pub fn get_font_size(&self, size: Size) -> String {
+        match size {
+            Size::XSmall => self.font_sizes.xsmall.clone(),
+            Size::Small => self.font_sizes.small.clone(),
+            Size::Medium => self.font_sizes.medium.clone(),
+            Size::Big => self.font_sizes.big.clone(),
+            Size::XLarge => self.font_sizes.xlarge.clone(),
+            Size::Custom(scale) => format!("calc({} * {})", self.font_sizes.medium, scale),
+        }
+    }
+
+    /// Spacing of the theme for the size
+    pub fn get_spacing(&self, size: Size) -> String {
+        match size {
+            Size::XSmall => self.spacings.xsmall.clone(),
+            Size::Small => self.spacings.small.clone(),
+            Size::Medium => self.spacings.medium.clone(),
+            Size::Big => self.spacings.big.clone(),
+            Size::XLarge => self.spacings.xlarge.clone(),
+            Size::Custom(scale) => format!("calc({} * {})", self.spacings.medium, scale),
+        }
+    }
+
+    /// Design tokens of the theme, the css custom property name without `--`
+    /// and its value. It can be serialized to share the theme with other projects
+    pub fn get_tokens(&self) -> ThemeTokens {
+        let mut tokens = ThemeTokens::new();
+
+        for palette in get_palettes() {
+            for style in get_styles() {
+                for theme_color in get_theme_colors() {
+                    tokens.insert(
+                        get_color_variable(palette.clone(), style.clone(), theme_color.clone())
+                            .trim_start_matches("--")
+                            .to_string(),
+                        self.get_color(palette.clone(), style.clone(), theme_color),
+                    );
+                }
+            }
+        }
+
+        for size in get_sizes() {
+            tokens.insert(
+                get_font_size_variable(size.clone())
+                    .trim_start_matches("--")
+                    .to_string(),
+                self.get_font_size(size.clone()),
+            );
+            tokens.insert(
+                get_spacing_variable(size.clone())
+                    .trim_start_matches("--")
+                    .to_string(),
+                self.get_spacing(size),
+            );
+        }
+
+        tokens
+    }
+
+    /// All the theme values as css custom properties declarations
+    pub fn get_css_variables(&self) -> String {
+        self.get_tokens()
+            .into_iter()
+            .map(|(name, value)| format!("--{}: {};\n", name, value))
+            .collect::<String>()
+    }
+
+    /// Css custom properties of the theme in a `:root` block,
+    /// useful to share the theme with pages which don't use yew styles
+    pub fn get_root_css(&self) -> String {
+        format!(":root {{\n{}}}\n", self.get_css_variables())
+    }
+}
+
+/// Css custom property name without `--` and its value
+pub type ThemeTokens = BTreeMap<String, String>;
+
+thread_local! {
+    static DEFAULT_THEME: Theme = Theme::default();
+}
+
+fn get_palettes() -> Vec<Palette> {
+    vec![
+        Palette::Standard,
+        Palette::Primary,
+        Palette::Secondary,
+        Palette::Success,
+        Palette::Info,
+        Palette::Link,
+        Palette::Warning,
+        Palette::Danger,
+        Palette::Clean,
+    ]
+}
+
+fn get_styles() -> Vec<Style> {
+    vec![
+        Style::Regular,
+        Style::Outline,
+        Style::Light,
+        Style::Ghost,
+        Style::Text,
+        Style::Gradient,
+    ]
+}
+
+fn get_gradient(color: &str, darken_amount: f64) -> String {
+    format!(
+        "linear-gradient(135deg, {}, {})",
+        darken_color(color, darken_amount),
+        darken_color(color, darken_amount + 15.0)
+    )
+}
+
+fn get_sizes() -> Vec<Size> {
+    vec![
+        Size::XSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Big,
+        Size::XLarge,
+    ]
+}
+
+fn get_theme_colors() -> Vec<ThemeColor> {
+    vec![
+        ThemeColor::Background,
+        ThemeColor::Color,
+        ThemeColor::Border,
+        ThemeColor::BorderColor,
+        ThemeColor::BackgroundFocus,
+        ThemeColor::BackgroundHover,
+        ThemeColor::BackgroundActive,
+        ThemeColor::BorderFocus,
+        ThemeColor::BorderHover,
+        ThemeColor::BorderActive,
+        ThemeColor::Overlay,
+    ]
+}
+
+fn get_theme_color_name(theme_color: ThemeColor) -> String {
+    match theme_color {
+        ThemeColor::Background => String::from("bg"),
+        ThemeColor::Color => String::from("color"),
+        ThemeColor::Border => String::from("border"),
+        ThemeColor::BorderColor => String::from("border-color"),
+        ThemeColor::BackgroundFocus => String::from("bg-focus"),
+        ThemeColor::BackgroundHover => String::from("bg-hover"),
+        ThemeColor::BackgroundActive => String::from("bg-active"),
+        ThemeColor::BorderFocus => String::from("border-color-focus"),
+        ThemeColor::BorderHover => String::from("border-color-hover"),
+        ThemeColor::BorderActive => String::from("border-color-active"),
+        ThemeColor::Overlay => String::from("overlay"),
+    }
+}
+
+/// Name of the css custom property of the theme color. Example: `--ys-primary-regular-bg`
+pub fn get_color_variable(palette: Palette, style: Style, theme_color: ThemeColor) -> String {
+    format!(
+        "--ys-{}-{}-{}",
+        get_palette(palette),
+        get_style(style),
+        get_theme_color_name(theme_color)
+    )
+}
+
+/// Name of the css custom property of the font size. Example: `--ys-font-size-medium`.
+/// Custom sizes use the medium one
+pub fn get_font_size_variable(size: Size) -> String {
+    match size {
+        Size::Custom(_) => get_font_size_variable(Size::Medium),
+        _ => format!("--ys-font-size-{}", get_size(size)),
+    }
+}
+
+/// Name of the css custom property of the spacing. Example: `--ys-spacing-medium`.
+/// Custom sizes use the medium one
+pub fn get_spacing_variable(size: Size) -> String {
+    match size {
+        Size::Custom(_) => get_spacing_variable(Size::Medium),
+        _ => format!("--ys-spacing-{}", get_size(size)),
+    }
+}
+
+/// Css value that reads the theme color from the closest `ThemeProvider`,
+/// otherwise takes the value of the default theme
+pub fn get_theme_color(palette: Palette, style: Style, theme_color: ThemeColor) -> String {
+    let default_value = DEFAULT_THEME
+        .with(|theme| theme.get_color(palette.clone(), style.clone(), theme_color.clone()));
+
+    format!(
+        "var({}, {})",
+        get_color_variable(palette, style, theme_color),
+        default_value
+    )
+}
+
+/// Css value that reads the font size from the closest `ThemeProvider`,
+/// otherwise takes the value of the default theme
+pub fn get_theme_font_size(size: Size) -> String {
+    if let Size::Custom(scale) = size {
+        return format!("calc({} * {})", get_theme_font_size(Size::Medium), scale);
+    }
+
+    let default_value = DEFAULT_THEME.with(|theme| theme.get_font_size(size.clone()));
+
+    format!("var({}, {})", get_font_size_variable(size), default_value)
+}
+
+/// Css value that reads the spacing from the closest `ThemeProvider`,
+/// otherwise takes the value of the default theme
+pub fn get_theme_spacing(size: Size) -> String {
+    if let Size::Custom(scale) = size {
+        return format!("calc({} * {})", get_theme_spacing(Size::Medium), scale);
+    }
+
+    let default_value = DEFAULT_THEME.with(|theme| theme.get_spacing(size.clone()));
+
+    format!("var({}, {})", get_spacing_variable(size), default_value)
+}
+
+/// Background, text color and border of the palette in the style.
+/// With interaction the background changes in focus, hover and active
+pub fn get_palette_css(palette: Palette, style: Style, interaction: bool) -> String {
+    let mut css = format!(
+        "background: {};
+        color: {};
+        border: {};\n",
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::Background),
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::Color),
+        get_theme_color(palette.clone(), style.clone(), ThemeColor::Border),
+    );
+
+    if style == Style::Gradient {
+        css.push_str("box-shadow: 0 2px 6px rgba(0, 0, 0, 0.25);\n");
+    }
+
+    if interaction && style == Style::Text {
+        css.push_str("&:hover { text-decoration: underline; }\n");
+    }
+
+    if interaction {
+        css.push_str(&format!(
+            "&:focus {{
+                background: {};
+            }}
+            &:hover {{
+                background: {};
+            }}
+            &:active {{
+                background: {};
+            }}\n",
+            get_theme_color(palette.clone(), style.clone(), ThemeColor::BackgroundFocus),
+            get_theme_color(palette.clone(), style.clone(), ThemeColor::BackgroundHover),
+            get_theme_color(palette, style, ThemeColor::BackgroundActive),
+        ));
+    }
+
+    css
+}
+
+/// Font size of the theme for the size
+pub fn get_font_size_css(size: Size) -> String {
+    format!("font-size: {};\n", get_theme_font_size(size))
+}
+
+/// Spacing of the property in each screen. Example: `gap`, `padding` or `margin`
+pub fn get_spacing_css(
+    breakpoints: &Breakpoints,
+    property: &str,
+    spacings: Vec<Responsive<Size>>,
+) -> String {
+    get_responsive_css(breakpoints, spacings, |size| {
+        format!("{}: {};", property, get_theme_spacing(size))
+    })
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_get_default_theme_colors() {
+    let theme = Theme::default();
+
+    assert_eq!(
+        theme.get_color(Palette::Primary, Style::Regular, ThemeColor::Background),
+        "#654016"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Primary, Style::Regular, ThemeColor::Border),
+        "none"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Danger, Style::Outline, ThemeColor::Border),
+        "1px solid #ed1c24"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Standard, Style::Regular, ThemeColor::BackgroundFocus),
+        "#848088"
+    );
+    assert_eq!(theme.get_font_size(Size::Big), "26px");
+}
+
+#[wasm_bindgen_test]
+fn should_get_dark_theme_colors() {
+    let theme = Theme::dark();
+
+    assert_eq!(
+        theme.get_color(Palette::Clean, Style::Regular, ThemeColor::Background),
+        "#1f1f22"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Danger, Style::Outline, ThemeColor::Border),
+        "1px solid #ff5a5f"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_read_theme_color_from_css_variable() {
+    assert_eq!(
+        get_theme_color(Palette::Info, Style::Light, ThemeColor::Color),
+        "var(--ys-info-light-color, #008fd5)"
+    );
+    assert_eq!(
+        get_theme_font_size(Size::Small),
+        "var(--ys-font-size-small, 12px)"
+    );
+    assert_eq!(
+        get_theme_font_size(Size::Custom(2.0)),
+        "calc(var(--ys-font-size-medium, 18px) * 2)"
+    );
+    assert_eq!(
+        get_spacing_css(
+            &Breakpoints::default(),
+            "gap",
+            vec![Responsive::Xs(Size::Small)]
+        ),
+        "gap: var(--ys-spacing-small, 8px);\n"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_create_css_variables_from_theme() {
+    let mut theme = Theme::default();
+
+    theme.success.regular = StyleColors::new("#2a9d8f", "#fff", None);
+
+    let variables = theme.get_css_variables();
+
+    assert!(variables.contains("--ys-success-regular-bg: #2a9d8f;"));
+    assert!(variables.contains("--ys-success-regular-bg-hover: "));
+    assert!(variables.contains("--ys-font-size-medium: 18px;"));
+}
+
+#[wasm_bindgen_test]
+fn should_get_custom_palette_colors() {
+    let brand = Palette::Custom(CustomPalette::new("brand", "#1d3557", "#fff", None));
+
+    assert_eq!(
+        get_theme_color(brand.clone(), Style::Regular, ThemeColor::Background),
+        "var(--ys-custom-brand-regular-bg, #1d3557)"
+    );
+    assert_eq!(
+        get_theme_color(brand.clone(), Style::Regular, ThemeColor::Border),
+        "var(--ys-custom-brand-regular-border, none)"
+    );
+    assert_eq!(
+        get_theme_color(brand.clone(), Style::Outline, ThemeColor::Border),
+        "var(--ys-custom-brand-outline-border, 1px solid #1d3557)"
+    );
+    assert_eq!(
+        Theme::dark()
+            .get_style_colors(brand, Style::Outline)
+            .background,
+        "var(--ys-clean-regular-bg, #1f1f22)"
+    );
+    assert_eq!(
+        get_palette(Palette::Custom(CustomPalette::new(
+            "Brand Blue;}",
+            "#1d3557",
+            "#fff",
+            None
+        ))),
+        "custom-brand-blue--"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_export_theme_tokens() {
+    let theme = Theme::default();
+    let tokens = theme.get_tokens();
+
+    assert_eq!(tokens.get("ys-primary-regular-bg"), Some(&"#654016".to_string()));
+    assert_eq!(tokens.get("ys-font-size-big"), Some(&"26px".to_string()));
+    assert_eq!(tokens.len(), 9 * 6 * 11 + 5 + 5);
+
+    let root_css = theme.get_root_css();
+
+    assert!(root_css.starts_with(":root {\n"));
+    assert!(root_css.contains("--ys-primary-regular-bg: #654016;\n"));
+}
+
+#[wasm_bindgen_test]
+fn should_get_ghost_text_and_gradient_colors() {
+    let theme = Theme::default();
+
+    assert_eq!(
+        theme.get_color(Palette::Primary, Style::Ghost, ThemeColor::Background),
+        "transparent"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Primary, Style::Ghost, ThemeColor::BackgroundFocus),
+        "#e9d7c4"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Primary, Style::Text, ThemeColor::Color),
+        "#654016"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Primary, Style::Text, ThemeColor::BackgroundHover),
+        "transparent"
+    );
+    assert_eq!(
+        theme.get_color(Palette::Primary, Style::Gradient, ThemeColor::Background),
+        format!(
+            "linear-gradient(135deg, #654016, {})",
+            darken_color("#654016", 15.0)
+        )
+    );
+}
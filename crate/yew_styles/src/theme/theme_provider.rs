@@ -0,0 +1,298 @@
+use super::{
+    get_color_mode, get_stored_color_mode, store_color_mode, ColorMode, StyleColors, Theme,
+    ThemeColor,
+};
+use crate::styles::{get_text_direction, Palette, Style, TextDirection};
+use stylist::{css, StyleSource};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::{utils, App};
+
+/// # Theme Provider component
+///
+/// Applies the theme to all the components included inside of it.
+/// The theme can be changed in runtime updating the `theme` prop
+///
+/// By default the color mode follows the `prefers-color-scheme` of the user,
+/// using `color_mode` prop it is possible to force light or dark mode,
+/// this choice is saved in the localStorage and it is applied in the next visits
+///
+/// With `dir` prop the components inside are mirrored in right to left languages
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_styles::button::Button;
+/// use yew_styles::styles::{Palette, Style};
+/// use yew_styles::theme::{StyleColors, Theme, ThemeProvider};
+///
+/// pub struct App {
+///     link: ComponentLink<Self>,
+///     theme: Theme,
+/// }
+///
+/// pub enum Msg {
+///     ChangeTheme,
+/// }
+///
+/// impl Component for App {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+///         App {
+///             link,
+///             theme: Theme::default(),
+///         }
+///     }
+///
+///     fn update(&mut self, msg: Self::Message) -> ShouldRender {
+///         match msg {
+///             Msg::ChangeTheme => {
+///                 self.theme.primary.regular = StyleColors::new("#1d3557", "#fff", None);
+///             }
+///         }
+///         true
+///     }
+///
+///     fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+///         false
+///     }
+///
+///     fn view(&self) -> Html {
+///        html! {
+///          <ThemeProvider theme=self.theme.clone()>
+///             <Button
+///                 onclick_signal=self.link.callback(|_| Msg::ChangeTheme)
+///                 button_palette=Palette::Primary
+///                 button_style=Style::Regular
+///             >{"Change theme"}</Button>
+///          </ThemeProvider>
+///        }
+///     }
+/// }
+/// ```
+pub struct ThemeProvider {
+    props: Props,
+    color_mode: ColorMode,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    /// Theme applied to the components. Default `Theme::default()`
+    #[prop_or_default]
+    pub theme: Theme,
+    /// Theme applied in dark mode. Default `Theme::dark()`
+    #[prop_or_else(Theme::dark)]
+    pub dark_theme: Theme,
+    /// Force the color mode and save it in the localStorage.
+    /// Without it, takes the color mode saved or `ColorMode::Auto`
+    #[prop_or_default]
+    pub color_mode: Option<ColorMode>,
+    /// Direction of the text of the components inside. Without it, inherits the direction
+    #[prop_or_default]
+    pub dir: Option<TextDirection>,
+    /// General property to get the ref of the component
+    #[prop_or_default]
+    pub code_ref: NodeRef,
+    /// General property to add keys
+    #[prop_or_default]
+    pub key: String,
+    /// General property to add custom class styles
+    #[prop_or_default]
+    pub class_name: String,
+    /// General property to add custom id
+    #[prop_or_default]
+    pub id: String,
+    /// Set css styles directly in the component
+    #[prop_or(css!(""))]
+    pub styles: StyleSource<'static>,
+    pub children: Children,
+}
+
+impl Component for ThemeProvider {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        let color_mode = get_provider_color_mode(props.color_mode.clone());
+
+        Self { props, color_mode }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.props.color_mode != props.color_mode {
+                self.color_mode = get_provider_color_mode(props.color_mode.clone());
+            }
+
+            self.props = props;
+            return true;
+        }
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class=classes!(
+                    "theme-provider",
+                    get_color_mode(self.color_mode.clone()),
+                    StyleSource::from(get_theme_css(
+                        &self.props.theme,
+                        &self.props.dark_theme,
+                        self.color_mode.clone(),
+                    )),
+                    self.props.class_name.clone(),
+                    self.props.styles.clone(),
+                )
+                id=self.props.id.clone()
+                dir=self.props.dir.clone().map(get_text_direction)
+                key=self.props.key.clone()
+                ref=self.props.code_ref.clone()
+            >
+                {self.props.children.clone()}
+            </div>
+        }
+    }
+}
+
+fn get_provider_color_mode(color_mode: Option<ColorMode>) -> ColorMode {
+    match color_mode {
+        Some(color_mode) => {
+            store_color_mode(color_mode.clone());
+            color_mode
+        }
+        None => get_stored_color_mode().unwrap_or(ColorMode::Auto),
+    }
+}
+
+fn get_dark_css(dark_theme: &Theme) -> String {
+    format!(
+        "color-scheme: dark;
+        background-color: {};
+        color: {};
+        {}",
+        dark_theme.get_color(Palette::Clean, Style::Regular, ThemeColor::Background),
+        dark_theme.get_color(Palette::Clean, Style::Regular, ThemeColor::Color),
+        dark_theme.get_css_variables(),
+    )
+}
+
+fn get_theme_css(theme: &Theme, dark_theme: &Theme, color_mode: ColorMode) -> String {
+    match color_mode {
+        ColorMode::Light => format!("color-scheme: light;\n{}", theme.get_css_variables()),
+        ColorMode::Dark => get_dark_css(dark_theme),
+        ColorMode::Auto => format!(
+            "{}@media (prefers-color-scheme: dark) {{
+                {}
+            }}\n",
+            theme.get_css_variables(),
+            get_dark_css(dark_theme),
+        ),
+    }
+}
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_create_theme_provider() {
+    let mut theme = Theme::default();
+
+    theme.primary.regular = StyleColors::new("#1d3557", "#fff", None);
+
+    let props = Props {
+        theme,
+        dark_theme: Theme::dark(),
+        color_mode: None,
+        dir: None,
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "theme-test".to_string(),
+        id: "theme-id-test".to_string(),
+        styles: css!("color: #000;"),
+        children: Children::new(vec![html! {<div id="result">{"result"}</div>}]),
+    };
+
+    let theme_provider: App<ThemeProvider> = App::new();
+
+    theme_provider.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let theme_provider_element = utils::document()
+        .get_element_by_id("theme-id-test")
+        .unwrap();
+    let child = theme_provider_element.first_element_child().unwrap();
+
+    assert_eq!(child.id(), "result".to_string());
+    assert_eq!(theme_provider_element.get_attribute("dir"), None);
+}
+
+#[wasm_bindgen_test]
+fn should_create_theme_provider_in_dark_mode() {
+    let props = Props {
+        theme: Theme::default(),
+        dark_theme: Theme::dark(),
+        color_mode: Some(ColorMode::Dark),
+        dir: None,
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "theme-test".to_string(),
+        id: "theme-dark-id-test".to_string(),
+        styles: css!(""),
+        children: Children::new(vec![html! {<div>{"result"}</div>}]),
+    };
+
+    let theme_provider: App<ThemeProvider> = App::new();
+
+    theme_provider.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let theme_provider_element = utils::document()
+        .get_element_by_id("theme-dark-id-test")
+        .unwrap();
+
+    assert!(theme_provider_element.class_list().contains("dark"));
+    assert_eq!(get_stored_color_mode(), Some(ColorMode::Dark));
+}
+
+#[wasm_bindgen_test]
+fn should_create_theme_provider_with_right_to_left_direction() {
+    let props = Props {
+        theme: Theme::default(),
+        dark_theme: Theme::dark(),
+        color_mode: None,
+        dir: Some(TextDirection::Rtl),
+        code_ref: NodeRef::default(),
+        key: "".to_string(),
+        class_name: "theme-test".to_string(),
+        id: "theme-rtl-id-test".to_string(),
+        styles: css!(""),
+        children: Children::new(vec![html! {<div>{"result"}</div>}]),
+    };
+
+    let theme_provider: App<ThemeProvider> = App::new();
+
+    theme_provider.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        props,
+    );
+
+    let theme_provider_element = utils::document()
+        .get_element_by_id("theme-rtl-id-test")
+        .unwrap();
+
+    assert_eq!(
+        theme_provider_element.get_attribute("dir"),
+        Some("rtl".to_string())
+    );
+}
@@ -1,93 +1,182 @@
-extern crate rand;
-extern crate wasm_bindgen;
-extern crate web_sys;
-use rand::{distributions::Alphanumeric, thread_rng, Rng};
-use wasm_bindgen::JsCast;
+use crate::styles::Size;
 use wasm_bindgen_test::*;
-use web_sys::{window, HtmlElement};
-use yew::utils;
 
-pub fn create_style(style: String, value: String, wrap: String) {
-    let element = get_html_element_by_class(&wrap, 0);
+/// Css of a custom size, the closure receives the scale to apply over the medium size values.
+/// The rest of sizes are styled by their class
+pub fn get_custom_size_css(size: Size, get_css: impl Fn(f64) -> String) -> String {
+    match size {
+        Size::Custom(scale) => get_css(scale),
+        _ => String::new(),
+    }
+}
+
+fn parse_hex_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.trim().strip_prefix('#')?;
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let expanded = match hex.len() {
+        3 => hex.chars().flat_map(|c| vec![c, c]).collect::<String>(),
+        6 => hex.to_string(),
+        _ => return None,
+    };
 
-    element.style().set_property(&style, &value).unwrap();
+    let red = u8::from_str_radix(&expanded[0..2], 16).ok()?;
+    let green = u8::from_str_radix(&expanded[2..4], 16).ok()?;
+    let blue = u8::from_str_radix(&expanded[4..6], 16).ok()?;
+
+    Some((red, green, blue))
 }
 
-pub fn get_random_string(len: usize) -> String {
-    thread_rng()
-        .sample_iter(&Alphanumeric)
-        .take(len)
-        .map(char::from)
-        .collect()
+fn rgb_to_hsl(red: u8, green: u8, blue: u8) -> (f64, f64, f64) {
+    let red = f64::from(red) / 255.0;
+    let green = f64::from(green) / 255.0;
+    let blue = f64::from(blue) / 255.0;
+
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = (max + min) / 2.0;
+
+    if (max - min).abs() < f64::EPSILON {
+        return (0.0, 0.0, lightness);
+    }
+
+    let delta = max - min;
+    let saturation = if lightness > 0.5 {
+        delta / (2.0 - max - min)
+    } else {
+        delta / (max + min)
+    };
+
+    let hue = if (max - red).abs() < f64::EPSILON {
+        (green - blue) / delta + if green < blue { 6.0 } else { 0.0 }
+    } else if (max - green).abs() < f64::EPSILON {
+        (blue - red) / delta + 2.0
+    } else {
+        (red - green) / delta + 4.0
+    };
+
+    (hue / 6.0, saturation, lightness)
 }
 
-pub fn get_html_element_by_class(class_name: &str, index: u32) -> HtmlElement {
-    utils::document()
-        .get_elements_by_class_name(class_name)
-        .get_with_index(index)
-        .unwrap()
-        .dyn_into::<HtmlElement>()
-        .unwrap()
+fn hue_to_rgb(p: f64, q: f64, hue: f64) -> f64 {
+    let hue = if hue < 0.0 {
+        hue + 1.0
+    } else if hue > 1.0 {
+        hue - 1.0
+    } else {
+        hue
+    };
+
+    if hue < 1.0 / 6.0 {
+        p + (q - p) * 6.0 * hue
+    } else if hue < 1.0 / 2.0 {
+        q
+    } else if hue < 2.0 / 3.0 {
+        p + (q - p) * (2.0 / 3.0 - hue) * 6.0
+    } else {
+        p
+    }
 }
 
-wasm_bindgen_test_configure!(run_in_browser);
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (u8, u8, u8) {
+    if saturation.abs() < f64::EPSILON {
+        let value = (lightness * 255.0).round() as u8;
+        return (value, value, value);
+    }
 
-#[wasm_bindgen_test]
-fn should_set_style_prop() {
-    let body = window().unwrap().document().unwrap().body().unwrap();
-
-    let element = window()
-        .unwrap()
-        .document()
-        .unwrap()
-        .create_element("div")
-        .unwrap();
-
-    element.set_text_content(Some("item"));
-    element.set_class_name("item");
-    body.append_child(&element).unwrap();
-
-    create_style(
-        "padding".to_string(),
-        "10px".to_string(),
-        "item".to_string(),
-    );
+    let q = if lightness < 0.5 {
+        lightness * (1.0 + saturation)
+    } else {
+        lightness + saturation - lightness * saturation
+    };
+    let p = 2.0 * lightness - q;
 
-    let item = window()
-        .unwrap()
-        .document()
-        .unwrap()
-        .get_elements_by_class_name("item")
-        .get_with_index(0)
-        .unwrap()
-        .dyn_into::<HtmlElement>()
-        .unwrap();
+    (
+        (hue_to_rgb(p, q, hue + 1.0 / 3.0) * 255.0).round() as u8,
+        (hue_to_rgb(p, q, hue) * 255.0).round() as u8,
+        (hue_to_rgb(p, q, hue - 1.0 / 3.0) * 255.0).round() as u8,
+    )
+}
 
-    let value = item.style().get_property_value("padding").unwrap();
+/// Darken a hex color reducing its lightness like the sass function `darken`.
+/// Colors that are not in hex format are returned without changes
+pub fn darken_color(color: &str, amount: f64) -> String {
+    match parse_hex_color(color) {
+        Some((red, green, blue)) => {
+            let (hue, saturation, lightness) = rgb_to_hsl(red, green, blue);
+            let (red, green, blue) =
+                hsl_to_rgb(hue, saturation, (lightness - amount / 100.0).max(0.0));
 
-    assert_eq!(value, "10px");
+            format!("#{:02x}{:02x}{:02x}", red, green, blue)
+        }
+        None => color.to_string(),
+    }
 }
 
-#[wasm_bindgen_test]
-fn should_generate_random_string() {
-    let mut random_values: Vec<String> = vec![];
-    let mut i = 0;
-    loop {
-        random_values.push(get_random_string(10));
-        i += 1;
-
-        if i == 1000 {
-            break;
+/// Lighten a hex color increasing its lightness like the sass function `lighten`.
+/// Colors that are not in hex format are returned without changes
+pub fn lighten_color(color: &str, amount: f64) -> String {
+    match parse_hex_color(color) {
+        Some((red, green, blue)) => {
+            let (hue, saturation, lightness) = rgb_to_hsl(red, green, blue);
+            let (red, green, blue) =
+                hsl_to_rgb(hue, saturation, (lightness + amount / 100.0).min(1.0));
+
+            format!("#{:02x}{:02x}{:02x}", red, green, blue)
         }
+        None => color.to_string(),
     }
+}
 
-    for (i, value) in random_values.iter().enumerate() {
-        let mut index = 0;
-        let repeat = &random_values.iter().any(move |random_value| {
-            let exist = random_value == value && i != index;
-            index += 1;
-            exist
-        });
-        assert!(!*repeat);
+/// Make a hex color more transparent like the sass function `transparentize`.
+/// Colors that are not in hex format are returned without changes
+pub fn transparentize_color(color: &str, amount: f64) -> String {
+    match parse_hex_color(color) {
+        Some((red, green, blue)) => {
+            format!("rgba({}, {}, {}, {})", red, green, blue, 1.0 - amount)
+        }
+        None => color.to_string(),
     }
 }
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn should_darken_color_like_sass() {
+    assert_eq!(darken_color("#918d94", 5.0), "#848088");
+    assert_eq!(darken_color("#654016", 10.0), "#3b250d");
+    assert_eq!(darken_color("#fff", 15.0), "#d9d9d9");
+    assert_eq!(darken_color("red", 15.0), "red");
+    assert_eq!(darken_color("#aébcd", 15.0), "#aébcd");
+}
+
+#[wasm_bindgen_test]
+fn should_transparentize_color_like_sass() {
+    assert_eq!(
+        transparentize_color("#918d94", 0.4),
+        "rgba(145, 141, 148, 0.6)"
+    );
+    assert_eq!(transparentize_color("red", 0.4), "red");
+}
+
+#[wasm_bindgen_test]
+fn should_lighten_color_like_sass() {
+    assert_eq!(lighten_color("#000", 50.0), "#808080");
+    assert_eq!(lighten_color("#654016", 40.0), "#dea769");
+    assert_eq!(lighten_color("#fff", 15.0), "#ffffff");
+    assert_eq!(lighten_color("red", 15.0), "red");
+}
+
+#[wasm_bindgen_test]
+fn should_get_css_only_for_custom_size() {
+    let get_css = |scale: f64| format!("padding: calc(5px * {});", scale);
+
+    assert_eq!(
+        get_custom_size_css(Size::Custom(1.5), get_css),
+        "padding: calc(5px * 1.5);"
+    );
+    assert_eq!(get_custom_size_css(Size::Big, get_css), "");
+}